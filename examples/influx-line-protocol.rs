@@ -0,0 +1,31 @@
+//! Print a forecast saved in a file as InfluxDB line protocol, eg for the Telegraf exec plugin.
+//!
+//! Usage: influx-line-protocol <hourly|three-hourly|daily> <path>
+use rjw_metoffice::{Daily, Forecast, Hourly, ThreeHourly};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let period = args
+        .next()
+        .expect("Provide the forecast period as the first argument");
+    let path = args
+        .next()
+        .expect("Provide a file path as the second argument");
+    let json = std::fs::read_to_string(&path).unwrap();
+    let lines = match period.as_str() {
+        "hourly" => json
+            .parse::<Forecast<Hourly>>()
+            .unwrap()
+            .to_line_protocol("metoffice_hourly"),
+        "three-hourly" => json
+            .parse::<Forecast<ThreeHourly>>()
+            .unwrap()
+            .to_line_protocol("metoffice_three_hourly"),
+        "daily" => json
+            .parse::<Forecast<Daily>>()
+            .unwrap()
+            .to_line_protocol("metoffice_daily"),
+        _ => panic!("Unknown forecast period {period:?}"),
+    };
+    print!("{lines}");
+}
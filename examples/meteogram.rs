@@ -0,0 +1,9 @@
+use rjw_metoffice::meteogram::Options;
+use rjw_metoffice::{Forecast, Hourly};
+
+fn main() {
+    let path = std::env::args().nth(1).unwrap();
+    let sample = std::fs::read_to_string(&path).unwrap();
+    let forecast: Forecast<Hourly> = sample.parse().unwrap();
+    print!("{}", forecast.to_meteogram(&Options::default()));
+}
@@ -0,0 +1,11 @@
+use rjw_metoffice::openmetrics::{DEFAULT_LEAD_TIMES, serve};
+use rjw_metoffice::{Forecast, Hourly};
+
+fn main() {
+    let path = std::env::args().nth(1).unwrap();
+    let sample = std::fs::read_to_string(&path).unwrap();
+    let forecast: Forecast<Hourly> = sample.parse().unwrap();
+    let listener = std::net::TcpListener::bind("127.0.0.1:9090").unwrap();
+    println!("Serving http://127.0.0.1:9090/metrics");
+    serve(&listener, || forecast.to_openmetrics(DEFAULT_LEAD_TIMES));
+}
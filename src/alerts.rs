@@ -0,0 +1,273 @@
+//! Rule-based weather alerts
+//!
+//! An alert [`Rule`] compares one named prediction field against a threshold, optionally
+//! requiring the condition to hold for some time within a limited lead time of the model run.
+//! Rules can be written in code or deserialised with serde from JSON, TOML or any other format:
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! use rjw_metoffice::alerts::{Severity, rules_from_json};
+//!
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let rules = rules_from_json(r#"[
+//!     {
+//!         "name": "Strong gusts",
+//!         "field": "wind_gust_speed",
+//!         "comparison": "above",
+//!         "threshold": 10.0,
+//!         "duration": "2h",
+//!         "max_lead_time": "24h",
+//!         "severity": "moderate"
+//!     }
+//! ]"#).unwrap();
+//! let alerts = forecast.alerts(&rules).unwrap();
+//! assert_eq!(alerts[0].rule, "Strong gusts");
+//! assert_eq!(alerts[0].severity, Severity::Moderate);
+//! assert_eq!(alerts[0].start.strftime("%H:%M").to_string(), "14:00");
+//! assert_eq!(alerts[0].end.strftime("%H:%M").to_string(), "18:00");
+//! ```
+//!
+//! Field names are those used by this crate's prediction structs, such as `temperature` or
+//! `lightning_probability`, in the crate's units (°C, m/s, Pa, %, …). Daily fields are prefixed
+//! with `day_` or `night_`, as in [`influx`](crate::influx). Durations are written as jiff
+//! parses them, for example `"3h"`, `"90 minutes"` or `"PT3H"`.
+//!
+//! Successive model runs usually repeat the same alerts. An [`AlertLog`] keeps track of the
+//! alerts already issued so that only new ones are passed on.
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use jiff::{SignedDuration, Timestamp, Zoned};
+use serde::{Deserialize, Deserializer};
+
+use crate::fields::{Field, Fields};
+use crate::{Daily, Error, Forecast, Hourly, ThreeHourly, TimePeriod};
+
+/// How a field value is compared with a rule's threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparison {
+    Above,
+    AtLeast,
+    Below,
+    AtMost,
+}
+
+impl Comparison {
+    fn test(self, value: f32, threshold: f32) -> bool {
+        match self {
+            Comparison::Above => value > threshold,
+            Comparison::AtLeast => value >= threshold,
+            Comparison::Below => value < threshold,
+            Comparison::AtMost => value <= threshold,
+        }
+    }
+
+    /// The further of two values from the threshold
+    fn extreme(self, a: f32, b: f32) -> f32 {
+        match self {
+            Comparison::Above | Comparison::AtLeast => a.max(b),
+            Comparison::Below | Comparison::AtMost => a.min(b),
+        }
+    }
+}
+
+/// Severity of an alert, from least to most severe
+///
+/// The levels are those of the Common Alerting Protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Minor,
+    Moderate,
+    Severe,
+    Extreme,
+}
+
+/// A condition on a single prediction field that raises an alert
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Rule {
+    /// Name of the rule, copied to its alerts
+    pub name: String,
+    /// Prediction field name, such as `wind_gust_speed`
+    pub field: String,
+    pub comparison: Comparison,
+    pub threshold: f32,
+    /// Shortest time for which the condition must hold, by default zero
+    ///
+    /// Each prediction counts for its whole period, so a single hourly prediction lasts an hour.
+    #[serde(default, deserialize_with = "duration")]
+    pub duration: SignedDuration,
+    /// Only predictions valid within this time of the model run are considered
+    #[serde(default, deserialize_with = "optional_duration")]
+    pub max_lead_time: Option<SignedDuration>,
+    pub severity: Severity,
+}
+
+fn duration<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SignedDuration, D::Error> {
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+fn optional_duration<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<SignedDuration>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|s| s.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Parse a JSON array of rules
+pub fn rules_from_json(json: &str) -> Result<Vec<Rule>, Error> {
+    serde_json::from_str(json).map_err(Error::Serde)
+}
+
+/// A period during which a rule's condition holds
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    /// Name of the rule that raised the alert
+    pub rule: String,
+    pub severity: Severity,
+    /// Start of the first prediction meeting the condition
+    pub start: Zoned,
+    /// End of the last prediction meeting the condition
+    pub end: Zoned,
+    /// Most extreme value of the field during the alert
+    ///
+    /// This is the highest value for `above` and `at_least` rules, and the lowest for `below`
+    /// and `at_most` rules.
+    pub extreme: f32,
+}
+
+impl Forecast<Hourly> {
+    /// Alerts raised by the rules, ordered by start time
+    ///
+    /// Returns [`Error::UnknownField`] if a rule names a field that none of the predictions have.
+    pub fn alerts(&self, rules: &[Rule]) -> Result<Vec<Alert>, Error> {
+        evaluate(self, rules)
+    }
+}
+
+impl Forecast<ThreeHourly> {
+    /// Alerts raised by the rules, ordered by start time
+    ///
+    /// Returns [`Error::UnknownField`] if a rule names a field that none of the predictions have.
+    pub fn alerts(&self, rules: &[Rule]) -> Result<Vec<Alert>, Error> {
+        evaluate(self, rules)
+    }
+}
+
+impl Forecast<Daily> {
+    /// Alerts raised by the rules, ordered by start time
+    ///
+    /// Day and night fields are prefixed with `day_` and `night_`. Returns
+    /// [`Error::UnknownField`] if a rule names a field that none of the predictions have.
+    pub fn alerts(&self, rules: &[Rule]) -> Result<Vec<Alert>, Error> {
+        evaluate(self, rules)
+    }
+}
+
+fn evaluate<T: TimePeriod + Fields>(
+    forecast: &Forecast<T>,
+    rules: &[Rule],
+) -> Result<Vec<Alert>, Error> {
+    let fields: Vec<Vec<Field>> = forecast.predictions.iter().map(Fields::fields).collect();
+    if let Some(rule) = rules
+        .iter()
+        .find(|rule| !fields.iter().flatten().any(|f| f.name == rule.field))
+    {
+        return Err(Error::UnknownField(rule.field.clone()));
+    }
+
+    let made_at = forecast.predictions_made_at.timestamp();
+    let mut alerts = Vec::new();
+    for rule in rules {
+        let horizon = rule.max_lead_time.map_or(Timestamp::MAX, |lead| {
+            made_at.saturating_add(lead).unwrap_or(Timestamp::MAX)
+        });
+        // First and last matching predictions of the current run, and the most extreme value.
+        let mut run: Option<(&T, &T, f32)> = None;
+        for (prediction, fields) in forecast.predictions.iter().zip(&fields) {
+            let value = (prediction.time().timestamp() < horizon)
+                .then(|| fields.iter().find(|f| f.name == rule.field))
+                .flatten()
+                .map(|f| f.value.as_f32())
+                .filter(|&v| rule.comparison.test(v, rule.threshold));
+            run = match (run, value) {
+                (Some((first, _, extreme)), Some(v)) => {
+                    Some((first, prediction, rule.comparison.extreme(extreme, v)))
+                }
+                (None, Some(v)) => Some((prediction, prediction, v)),
+                (Some(finished), None) => {
+                    alerts.extend(alert(rule, finished));
+                    None
+                }
+                (None, None) => None,
+            };
+        }
+        if let Some(finished) = run {
+            alerts.extend(alert(rule, finished));
+        }
+    }
+    alerts.sort_by(|a, b| a.start.cmp(&b.start));
+    Ok(alerts)
+}
+
+/// Alert for a run of matching predictions, if it lasts long enough
+fn alert<T: TimePeriod>(rule: &Rule, (first, last, extreme): (&T, &T, f32)) -> Option<Alert> {
+    let start = first.time().clone();
+    let end = last.time() + T::PERIOD;
+    (end.duration_since(&start) >= rule.duration).then(|| Alert {
+        rule: rule.name.clone(),
+        severity: rule.severity,
+        start,
+        end,
+        extreme,
+    })
+}
+
+/// Record of issued alerts, for de-duplicating alerts across model runs
+///
+/// An alert is a duplicate if an alert from the same rule has already been issued for an
+/// overlapping period. The recorded period is updated to the latest run's, so an alert that
+/// drifts later from run to run is still recognised.
+#[derive(Debug, Clone, Default)]
+pub struct AlertLog {
+    issued: Vec<Alert>,
+}
+
+impl AlertLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Alerts that have not already been issued, which are recorded as issued
+    pub fn deduplicate(&mut self, alerts: impl IntoIterator<Item = Alert>) -> Vec<Alert> {
+        let mut fresh = Vec::new();
+        for alert in alerts {
+            let previous = self.issued.iter_mut().find(|issued| {
+                issued.rule == alert.rule && issued.start < alert.end && alert.start < issued.end
+            });
+            match previous {
+                Some(previous) => *previous = alert,
+                None => {
+                    self.issued.push(alert.clone());
+                    fresh.push(alert);
+                }
+            }
+        }
+        fresh
+    }
+
+    /// Forget alerts that ended at or before `time`
+    pub fn forget_before(&mut self, time: impl Into<Timestamp>) {
+        let time = time.into();
+        self.issued.retain(|alert| alert.end.timestamp() > time);
+    }
+
+    /// Alerts issued so far, with their most recent periods
+    pub fn issued(&self) -> &[Alert] {
+        &self.issued
+    }
+}
@@ -0,0 +1,261 @@
+//! Beaufort scale classification of wind speeds
+//!
+//! The Met Office describes wind to the public using the [Beaufort scale], from force 0 (calm) to
+//! force 12 (hurricane force). Any wind speed can be classified with `Beaufort::from`, and each
+//! prediction type has helpers for the force of its mean wind and gusts:
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! use rjw_metoffice::beaufort::Beaufort;
+//! use rjw_metoffice::units::MetresPerSecond;
+//!
+//! assert_eq!(Beaufort::from(MetresPerSecond(9.0)), Beaufort::FreshBreeze);
+//! assert_eq!(Beaufort::FreshBreeze.force(), 5);
+//! assert_eq!(Beaufort::FreshBreeze.to_string(), "Fresh breeze");
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let gusts = forecast.predictions[0].gust_force();
+//! ```
+//!
+//! Wind speeds are classified using the WMO ranges in metres per second. These are given to one
+//! decimal place (force 1 is 0.3–1.5 m/s, force 2 is 1.6–3.3 m/s), so each force starts halfway
+//! between the top of the range below and the bottom of its own, as given in the table below.
+//! Speeds between the rounded ranges (for example 1.55 m/s) fall into the higher force.
+//!
+//! | Force | Name | Lower bound (m/s) |
+//! |------:|------|------------------:|
+//! | 0 | Calm | 0 |
+//! | 1 | Light air | 0.25 |
+//! | 2 | Light breeze | 1.55 |
+//! | 3 | Gentle breeze | 3.35 |
+//! | 4 | Moderate breeze | 5.45 |
+//! | 5 | Fresh breeze | 7.95 |
+//! | 6 | Strong breeze | 10.75 |
+//! | 7 | Near gale | 13.85 |
+//! | 8 | Gale | 17.15 |
+//! | 9 | Strong gale | 20.75 |
+//! | 10 | Storm | 24.45 |
+//! | 11 | Violent storm | 28.45 |
+//! | 12 | Hurricane force | 32.65 |
+//!
+//! [Beaufort scale]: https://www.metoffice.gov.uk/weather/guides/coast-and-sea/beaufort-scale
+use crate::daily::{Day, Night};
+use crate::units::MetresPerSecond;
+use crate::{Hourly, ThreeHourly};
+
+/// Beaufort wind force
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Beaufort {
+    Calm,
+    LightAir,
+    LightBreeze,
+    GentleBreeze,
+    ModerateBreeze,
+    FreshBreeze,
+    StrongBreeze,
+    NearGale,
+    Gale,
+    StrongGale,
+    Storm,
+    ViolentStorm,
+    Hurricane,
+}
+
+/// Every force, in order, with the lowest wind speed in metres per second for that force
+const SCALE: [(Beaufort, f32); 13] = [
+    (Beaufort::Calm, 0.0),
+    (Beaufort::LightAir, 0.25),
+    (Beaufort::LightBreeze, 1.55),
+    (Beaufort::GentleBreeze, 3.35),
+    (Beaufort::ModerateBreeze, 5.45),
+    (Beaufort::FreshBreeze, 7.95),
+    (Beaufort::StrongBreeze, 10.75),
+    (Beaufort::NearGale, 13.85),
+    (Beaufort::Gale, 17.15),
+    (Beaufort::StrongGale, 20.75),
+    (Beaufort::Storm, 24.45),
+    (Beaufort::ViolentStorm, 28.45),
+    (Beaufort::Hurricane, 32.65),
+];
+
+impl Beaufort {
+    /// Force number, from 0 (calm) to 12 (hurricane force)
+    pub fn force(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Lowest wind speed classified as this force
+    pub fn lower_bound(&self) -> MetresPerSecond {
+        MetresPerSecond(SCALE[self.force() as usize].1)
+    }
+
+    /// Name of this force, such as "Fresh breeze"
+    pub fn name(&self) -> &'static str {
+        use Beaufort::*;
+        match self {
+            Calm => "Calm",
+            LightAir => "Light air",
+            LightBreeze => "Light breeze",
+            GentleBreeze => "Gentle breeze",
+            ModerateBreeze => "Moderate breeze",
+            FreshBreeze => "Fresh breeze",
+            StrongBreeze => "Strong breeze",
+            NearGale => "Near gale",
+            Gale => "Gale",
+            StrongGale => "Strong gale",
+            Storm => "Storm",
+            ViolentStorm => "Violent storm",
+            Hurricane => "Hurricane force",
+        }
+    }
+
+    /// Appearance of the open sea at this force
+    pub fn sea_description(&self) -> &'static str {
+        use Beaufort::*;
+        match self {
+            Calm => "Sea like a mirror",
+            LightAir => "Ripples with the appearance of scales are formed, without foam crests",
+            LightBreeze => {
+                "Small wavelets, still short but more pronounced; \
+                 crests have a glassy appearance and do not break"
+            }
+            GentleBreeze => {
+                "Large wavelets; crests begin to break; foam of glassy appearance; \
+                 perhaps scattered white horses"
+            }
+            ModerateBreeze => "Small waves, becoming longer; fairly frequent white horses",
+            FreshBreeze => {
+                "Moderate waves, taking a more pronounced long form; \
+                 many white horses are formed; chance of some spray"
+            }
+            StrongBreeze => {
+                "Large waves begin to form; the white foam crests are more extensive everywhere; \
+                 probably some spray"
+            }
+            NearGale => {
+                "Sea heaps up and white foam from breaking waves begins to be blown in streaks \
+                 along the direction of the wind"
+            }
+            Gale => {
+                "Moderately high waves of greater length; edges of crests begin to break into \
+                 spindrift; foam is blown in well-marked streaks along the direction of the wind"
+            }
+            StrongGale => {
+                "High waves; dense streaks of foam along the direction of the wind; \
+                 crests of waves begin to topple, tumble and roll over; spray may affect visibility"
+            }
+            Storm => {
+                "Very high waves with long overhanging crests; the surface of the sea takes on \
+                 a white appearance; the tumbling of the sea becomes heavy and shock-like; \
+                 visibility affected"
+            }
+            ViolentStorm => {
+                "Exceptionally high waves; the sea is completely covered with long white patches \
+                 of foam; visibility affected"
+            }
+            Hurricane => {
+                "The air is filled with foam and spray; sea completely white with driving spray; \
+                 visibility very seriously affected"
+            }
+        }
+    }
+
+    /// Effects of this force on land
+    pub fn land_description(&self) -> &'static str {
+        use Beaufort::*;
+        match self {
+            Calm => "Calm; smoke rises vertically",
+            LightAir => "Direction of wind shown by smoke drift, but not by wind vanes",
+            LightBreeze => "Wind felt on face; leaves rustle; ordinary vanes moved by wind",
+            GentleBreeze => "Leaves and small twigs in constant motion; wind extends light flag",
+            ModerateBreeze => "Raises dust and loose paper; small branches are moved",
+            FreshBreeze => {
+                "Small trees in leaf begin to sway; crested wavelets form on inland waters"
+            }
+            StrongBreeze => {
+                "Large branches in motion; whistling heard in telegraph wires; \
+                 umbrellas used with difficulty"
+            }
+            NearGale => "Whole trees in motion; inconvenience felt when walking against the wind",
+            Gale => "Twigs break off trees; generally impedes progress",
+            StrongGale => "Slight structural damage occurs; chimney pots and slates removed",
+            Storm => {
+                "Seldom experienced inland; trees uprooted; considerable structural damage occurs"
+            }
+            ViolentStorm => "Very rarely experienced; accompanied by widespread damage",
+            Hurricane => "Devastation",
+        }
+    }
+}
+
+impl From<MetresPerSecond> for Beaufort {
+    fn from(speed: MetresPerSecond) -> Self {
+        SCALE
+            .iter()
+            .rev()
+            .find(|(_, lower)| speed.0 >= *lower)
+            .map_or(Beaufort::Calm, |(force, _)| *force)
+    }
+}
+
+impl core::fmt::Display for Beaufort {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Hourly {
+    /// Beaufort force of the mean wind speed
+    pub fn wind_force(&self) -> Beaufort {
+        self.wind_speed.into()
+    }
+
+    /// Beaufort force of the wind gust speed
+    pub fn gust_force(&self) -> Beaufort {
+        self.wind_gust_speed.into()
+    }
+}
+
+impl ThreeHourly {
+    /// Beaufort force of the mean wind speed
+    pub fn wind_force(&self) -> Beaufort {
+        self.wind_speed.into()
+    }
+
+    /// Beaufort force of the wind gust speed
+    pub fn gust_force(&self) -> Beaufort {
+        self.wind_gust_speed.into()
+    }
+}
+
+impl Day {
+    /// Beaufort force of the mean wind speed at midday
+    pub fn wind_force(&self) -> Beaufort {
+        match self {
+            Day::Past { wind_speed, .. } | Day::Future { wind_speed, .. } => (*wind_speed).into(),
+        }
+    }
+
+    /// Beaufort force of the wind gust speed at midday
+    pub fn gust_force(&self) -> Beaufort {
+        match self {
+            Day::Past {
+                wind_gust_speed, ..
+            }
+            | Day::Future {
+                wind_gust_speed, ..
+            } => (*wind_gust_speed).into(),
+        }
+    }
+}
+
+impl Night {
+    /// Beaufort force of the mean wind speed at midnight
+    pub fn wind_force(&self) -> Beaufort {
+        self.wind_speed.into()
+    }
+
+    /// Beaufort force of the wind gust speed at midnight
+    pub fn gust_force(&self) -> Beaufort {
+        self.wind_gust_speed.into()
+    }
+}
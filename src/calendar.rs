@@ -0,0 +1,176 @@
+//! Grouping predictions by local calendar day
+//!
+//! Prediction times are in UTC, but a "day" for most purposes is a date in a particular time
+//! zone. These methods group and slice a forecast by local date, so that "today" in London runs
+//! from 23:00 UTC the day before while British Summer Time is in force:
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! use jiff::tz::TimeZone;
+//!
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let london = TimeZone::posix("GMT0BST,M3.5.0/1,M10.5.0").unwrap();
+//! let tomorrow = forecast.tomorrow(&london);
+//! assert_eq!(tomorrow.len(), 24);
+//! assert_eq!(tomorrow[0].time.to_string(), "2023-07-05T23:00:00+00:00[UTC]");
+//! ```
+//!
+//! Local days are not always 24 hours long. On the days the clocks change, a day is 23 or 25
+//! hours long and holds one fewer or one more hourly prediction; see [`LocalDay::length`].
+//!
+//! "Today" is the date on which the model was run, in the given time zone. Daily predictions
+//! are for a whole date and fall on that date whatever the time zone.
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use jiff::civil::{Date, Weekday};
+use jiff::tz::TimeZone;
+use jiff::{SignedDuration, Span, Zoned};
+
+use crate::summary::{Extremes, Summarise};
+use crate::units::{Celsius, Conditions, MetresPerSecond};
+use crate::{Forecast, Hourly, ThreeHourly, TimePeriod};
+
+/// Predictions falling on a single local calendar date
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalDay<'a, T> {
+    pub date: Date,
+    /// Start of the day in the time zone used for grouping
+    pub start: Zoned,
+    /// Start of the following day
+    pub end: Zoned,
+    pub predictions: &'a [T],
+}
+
+impl<T> LocalDay<'_, T> {
+    /// Length of the day, which is 23 or 25 hours when the clocks change
+    pub fn length(&self) -> SignedDuration {
+        self.end.duration_since(&self.start)
+    }
+}
+
+/// Summary of the predictions for a local calendar date
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaySummary {
+    pub date: Date,
+    /// Length of the day, which is 23 or 25 hours when the clocks change
+    pub length: SignedDuration,
+    /// Number of predictions within the day
+    ///
+    /// Days at the start and end of the forecast may be only partly covered.
+    pub predictions: usize,
+    /// Most significant conditions, by [`Conditions::severity`]
+    pub conditions: Conditions,
+    /// Lowest temperature, including any minimum given with the predictions
+    pub temperature_minimum: Celsius,
+    /// Highest temperature, including any maximum given with the predictions
+    pub temperature_maximum: Celsius,
+    /// Strongest gust, including any maximum gust given with the predictions
+    pub wind_gust_maximum: MetresPerSecond,
+}
+
+impl<T: TimePeriod> Forecast<T> {
+    /// Predictions grouped by their calendar date in the time zone `tz`
+    ///
+    /// Days are in order, and only days with at least one prediction are included.
+    pub fn local_days<'a>(
+        &'a self,
+        tz: &TimeZone,
+    ) -> impl Iterator<Item = LocalDay<'a, T>> + use<'a, T> {
+        let (group_tz, tz) = (tz.clone(), tz.clone());
+        self.predictions
+            .chunk_by(move |a, b| a.local_date(&group_tz) == b.local_date(&group_tz))
+            .filter_map(move |predictions| {
+                let date = predictions.first()?.local_date(&tz);
+                Some(LocalDay {
+                    date,
+                    start: date.to_zoned(tz.clone()).ok()?,
+                    end: date.tomorrow().ok()?.to_zoned(tz.clone()).ok()?,
+                    predictions,
+                })
+            })
+    }
+
+    /// Predictions falling on the calendar date `date` in the time zone `tz`
+    pub fn on_date(&self, tz: &TimeZone, date: Date) -> &[T] {
+        self.on_dates(tz, date..=date)
+    }
+
+    /// Predictions falling within a range of calendar dates in the time zone `tz`
+    pub fn on_dates(&self, tz: &TimeZone, dates: RangeInclusive<Date>) -> &[T] {
+        let from = self
+            .predictions
+            .partition_point(|p| p.local_date(tz) < *dates.start());
+        let to = self
+            .predictions
+            .partition_point(|p| p.local_date(tz) <= *dates.end());
+        self.predictions.get(from..to).unwrap_or_default()
+    }
+
+    /// Predictions for the date on which the model was run, in the time zone `tz`
+    pub fn today(&self, tz: &TimeZone) -> &[T] {
+        self.on_date(tz, self.today_in(tz))
+    }
+
+    /// Predictions for the day after the model was run, in the time zone `tz`
+    pub fn tomorrow(&self, tz: &TimeZone) -> &[T] {
+        match self.today_in(tz).tomorrow() {
+            Ok(tomorrow) => self.on_date(tz, tomorrow),
+            Err(_) => &[],
+        }
+    }
+
+    /// Predictions for the coming Saturday and Sunday, in the time zone `tz`
+    ///
+    /// If the model was run on a Saturday or Sunday, this is the current weekend.
+    pub fn weekend(&self, tz: &TimeZone) -> &[T] {
+        let today = self.today_in(tz);
+        let saturday = match today.weekday() {
+            Weekday::Sunday => today.yesterday(),
+            weekday => today.checked_add(Span::new().days(weekday.until(Weekday::Saturday))),
+        };
+        let Some((saturday, sunday)) = saturday
+            .ok()
+            .and_then(|saturday| Some((saturday, saturday.tomorrow().ok()?)))
+        else {
+            return &[];
+        };
+        self.on_dates(tz, saturday..=sunday)
+    }
+
+    fn today_in(&self, tz: &TimeZone) -> Date {
+        self.predictions_made_at.with_time_zone(tz.clone()).date()
+    }
+}
+
+impl Forecast<Hourly> {
+    /// Summary of each calendar date in the time zone `tz`
+    pub fn day_summaries(&self, tz: &TimeZone) -> Vec<DaySummary> {
+        summarise_days(self, tz)
+    }
+}
+
+impl Forecast<ThreeHourly> {
+    /// Summary of each calendar date in the time zone `tz`
+    pub fn day_summaries(&self, tz: &TimeZone) -> Vec<DaySummary> {
+        summarise_days(self, tz)
+    }
+}
+
+fn summarise_days<T: Summarise>(forecast: &Forecast<T>, tz: &TimeZone) -> Vec<DaySummary> {
+    forecast
+        .local_days(tz)
+        .filter_map(|day| {
+            let extremes = Extremes::of(day.predictions)?;
+            Some(DaySummary {
+                date: day.date,
+                length: day.length(),
+                predictions: day.predictions.len(),
+                conditions: extremes.conditions,
+                temperature_minimum: extremes.low,
+                temperature_maximum: extremes.high,
+                wind_gust_maximum: extremes.gust,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,155 @@
+//! Compass directions and wind vectors
+//!
+//! Wind directions are given as [`Degrees`] clockwise from north, and are the direction the wind
+//! is blowing *from*. They can be named on an 8, 16 or 32-point compass rose, drawn as an arrow,
+//! and converted to and from u/v wind components:
+//!
+//! ```
+//! use rjw_metoffice::compass::{CompassPoints, WindVector};
+//! use rjw_metoffice::units::{Degrees, MetresPerSecond};
+//!
+//! let direction = Degrees(292.0);
+//! assert_eq!(direction.compass(CompassPoints::Eight), "W");
+//! assert_eq!(direction.compass(CompassPoints::Sixteen), "WNW");
+//! assert_eq!(direction.arrow(), '→');
+//!
+//! let wind = WindVector::new(MetresPerSecond(10.0), Degrees(270.0));
+//! assert!((wind.u.0 - 10.0).abs() < 1e-4); // A westerly blows towards the east.
+//! ```
+//!
+//! Directions cannot be averaged arithmetically (the mean of 350° and 10° is north, not south),
+//! so use [`mean_direction`] or [`WindVector::mean`] instead.
+use crate::units::{Degrees, MetresPerSecond};
+use crate::{Forecast, Hourly, ThreeHourly};
+
+/// Number of points on the compass rose used to name a direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompassPoints {
+    /// Cardinal and intercardinal directions, such as "NE"
+    Eight,
+    /// Adds the secondary intercardinal directions, such as "ENE"
+    Sixteen,
+    /// Adds the "by" points, such as "EbN" (east by north)
+    ThirtyTwo,
+}
+
+/// Names of the 32 compass points, clockwise from north
+const POINTS: [&str; 32] = [
+    "N", "NbE", "NNE", "NEbN", "NE", "NEbE", "ENE", "EbN", "E", "EbS", "ESE", "SEbE", "SE", "SEbS",
+    "SSE", "SbE", "S", "SbW", "SSW", "SWbS", "SW", "SWbW", "WSW", "WbS", "W", "WbN", "WNW", "NWbW",
+    "NW", "NWbN", "NNW", "NbW",
+];
+
+/// Arrows showing the direction the wind blows towards, for winds from N, NE, E, …
+const ARROWS: [char; 8] = ['↓', '↙', '←', '↖', '↑', '↗', '→', '↘'];
+
+impl Degrees {
+    /// Equivalent direction in the range [0°, 360°)
+    pub fn normalised(&self) -> Degrees {
+        let mut d = libm::fmodf(self.0, 360.0);
+        if d < 0.0 {
+            d += 360.0;
+        }
+        // Adding 360 to a tiny negative remainder can round up to exactly 360
+        Degrees(if d >= 360.0 { 0.0 } else { d })
+    }
+
+    /// Name of the nearest point on a compass rose with the given number of points
+    pub fn compass(&self, points: CompassPoints) -> &'static str {
+        let step = match points {
+            CompassPoints::Eight => 4,
+            CompassPoints::Sixteen => 2,
+            CompassPoints::ThirtyTwo => 1,
+        };
+        POINTS[self.sector(32 / step) * step]
+    }
+
+    /// Arrow pointing in the direction the wind is blowing towards
+    ///
+    /// A northerly wind (0°) blows towards the south, so is drawn as '↓'.
+    pub fn arrow(&self) -> char {
+        ARROWS[self.sector(8)]
+    }
+
+    /// Index of the nearest of `n` equal sectors centred on north, clockwise
+    fn sector(&self, n: usize) -> usize {
+        let width = 360.0 / n as f32;
+        let i = libm::floorf((self.normalised().0 + width / 2.0) / width) as usize;
+        i % n
+    }
+}
+
+/// Wind as eastward (u) and northward (v) components
+///
+/// This follows the meteorological convention: a positive `u` is wind blowing towards the east
+/// (a westerly), and a positive `v` is wind blowing towards the north (a southerly).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindVector {
+    pub u: MetresPerSecond,
+    pub v: MetresPerSecond,
+}
+
+impl WindVector {
+    /// Components of a wind of the given speed blowing from `direction`
+    pub fn new(speed: MetresPerSecond, direction: Degrees) -> Self {
+        let radians = direction.0.to_radians();
+        Self {
+            u: MetresPerSecond(-speed.0 * libm::sinf(radians)),
+            v: MetresPerSecond(-speed.0 * libm::cosf(radians)),
+        }
+    }
+
+    /// Wind speed
+    pub fn speed(&self) -> MetresPerSecond {
+        MetresPerSecond(libm::hypotf(self.u.0, self.v.0))
+    }
+
+    /// Direction the wind is blowing from, in the range [0°, 360°)
+    ///
+    /// Calm winds, with both components zero, are given as 0°.
+    pub fn direction(&self) -> Degrees {
+        let radians = libm::atan2f(-self.u.0, -self.v.0);
+        Degrees(radians.to_degrees()).normalised()
+    }
+
+    /// Vector mean of the given winds, or `None` if there are none
+    ///
+    /// The direction of the mean is weighted by wind speed, and its speed is reduced when the
+    /// winds blow in different directions. For the mean direction alone, see [`mean_direction`].
+    pub fn mean(winds: impl IntoIterator<Item = WindVector>) -> Option<WindVector> {
+        let (mut u, mut v, mut n) = (0.0, 0.0, 0);
+        for wind in winds {
+            u += wind.u.0;
+            v += wind.v.0;
+            n += 1;
+        }
+        (n > 0).then(|| WindVector {
+            u: MetresPerSecond(u / n as f32),
+            v: MetresPerSecond(v / n as f32),
+        })
+    }
+}
+
+/// Circular mean of the given directions, each weighted equally
+///
+/// Returns `None` if there are no directions, or if they cancel out so that there is no mean
+/// direction (for example, 0° and 180°).
+pub fn mean_direction(directions: impl IntoIterator<Item = Degrees>) -> Option<Degrees> {
+    let unit = MetresPerSecond(1.0);
+    let mean = WindVector::mean(directions.into_iter().map(|d| WindVector::new(unit, d)))?;
+    (mean.speed().0 > 1e-4).then(|| mean.direction())
+}
+
+impl Forecast<Hourly> {
+    /// Circular mean of the wind direction over all predictions
+    pub fn mean_wind_direction(&self) -> Option<Degrees> {
+        mean_direction(self.predictions.iter().map(|h| h.wind_direction))
+    }
+}
+
+impl Forecast<ThreeHourly> {
+    /// Circular mean of the wind direction over all predictions
+    pub fn mean_wind_direction(&self) -> Option<Degrees> {
+        mean_direction(self.predictions.iter().map(|t| t.wind_direction))
+    }
+}
@@ -3,7 +3,7 @@
 use crate::Error;
 use crate::parse::RawDailyForecast;
 use crate::units::{
-    Celsius, Conditions, Degrees, Metres, MetresPerSecond, Pascals, Percentage, UvIndex,
+    Celsius, Conditions, Degrees, MetresPerSecond, Pascals, Percentage, UvIndex, Visibility,
 };
 
 /// Forecast for a particular day and the following night
@@ -106,7 +106,7 @@ pub enum Day {
         /// Air pressure at mean sea level at midday
         pressure: Pascals,
         /// Visibility in metres at midday
-        visibility: Metres,
+        visibility: Visibility,
         /// Mean wind speed at midday
         ///
         /// This is the mean speed over the 10 minutes to midday and is measured at 10m
@@ -162,7 +162,7 @@ pub enum Day {
         /// Maximum UV index
         uv_index_maximum: UvIndex,
         /// Visibility in metres at midday
-        visibility: Metres,
+        visibility: Visibility,
         /// Mean wind speed at midday
         ///
         /// This is the mean speed over the 10 minutes to midday and is measured at 10m
@@ -226,7 +226,7 @@ pub struct Night {
     /// Air pressure at mean sea level at midnight
     pub pressure: Pascals,
     /// Visibility in metres at midnight
-    pub visibility: Metres,
+    pub visibility: Visibility,
     /// Mean wind speed at midnight
     ///
     /// This is the mean speed over the 10 minutes to midnight and is measured at 10m
@@ -250,12 +250,12 @@ impl TryFrom<RawDailyForecast> for Daily {
     fn try_from(rf: RawDailyForecast) -> Result<Self, Self::Error> {
         // Check for a key known to be missing in the previous day's data.
         // Perhaps this is not robust?
-        let day = if rf.day_max_feels_like_temp.is_none() {
-            Day::Past {
+        let day = match rf.day_max_feels_like_temp {
+            None => Day::Past {
                 wind_speed: MetresPerSecond(rf.midday_10m_wind_speed),
                 wind_direction: Degrees(rf.midday_10m_wind_direction),
                 wind_gust_speed: MetresPerSecond(rf.midday_10m_wind_gust),
-                visibility: Metres(rf.midday_visibility),
+                visibility: Visibility(rf.midday_visibility),
                 relative_humidity: Percentage(rf.midday_relative_humidity),
                 pressure: Pascals(rf.midday_mslp),
                 temperature_maximum: TemperaturePrediction {
@@ -269,13 +269,12 @@ impl TryFrom<RawDailyForecast> for Daily {
                 temperature_feels_like_maximum_lower_bound: Celsius(
                     rf.day_lower_bound_max_feels_like_temp,
                 ),
-            }
-        } else {
-            Day::Future {
+            },
+            Some(feels_like_maximum) => Day::Future {
                 wind_speed: MetresPerSecond(rf.midday_10m_wind_speed),
                 wind_direction: Degrees(rf.midday_10m_wind_direction),
                 wind_gust_speed: MetresPerSecond(rf.midday_10m_wind_gust),
-                visibility: Metres(rf.midday_visibility),
+                visibility: Visibility(rf.midday_visibility),
                 relative_humidity: Percentage(rf.midday_relative_humidity),
                 pressure: Pascals(rf.midday_mslp),
                 uv_index_maximum: UvIndex(rf.max_uv_index.unwrap()),
@@ -286,7 +285,7 @@ impl TryFrom<RawDailyForecast> for Daily {
                     lower_bound: Celsius(rf.day_lower_bound_max_temp),
                 },
                 temperature_feels_like_maximum: TemperaturePrediction {
-                    most_likely: Celsius(rf.day_max_feels_like_temp.unwrap()),
+                    most_likely: Celsius(feels_like_maximum),
                     upper_bound: Celsius(rf.day_upper_bound_max_feels_like_temp),
                     lower_bound: Celsius(rf.day_lower_bound_max_feels_like_temp),
                 },
@@ -297,7 +296,7 @@ impl TryFrom<RawDailyForecast> for Daily {
                 heavy_snow_probability: Percentage(rf.day_probability_of_heavy_snow.unwrap()),
                 hail_probability: Percentage(rf.day_probability_of_hail.unwrap()),
                 lightning_probability: Percentage(rf.day_probability_of_sferics.unwrap()),
-            }
+            },
         };
 
         Ok(Self {
@@ -307,7 +306,7 @@ impl TryFrom<RawDailyForecast> for Daily {
                 wind_speed: MetresPerSecond(rf.midnight_10m_wind_speed),
                 wind_direction: Degrees(rf.midnight_10m_wind_direction),
                 wind_gust_speed: MetresPerSecond(rf.midnight_10m_wind_gust),
-                visibility: Metres(rf.midnight_visibility),
+                visibility: Visibility(rf.midnight_visibility),
                 relative_humidity: Percentage(rf.midnight_relative_humidity),
                 pressure: Pascals(rf.midnight_mslp),
                 conditions: rf.night_significant_weather_code.try_into()?,
@@ -5,8 +5,22 @@ pub enum Error {
     Serde(serde_json::Error),
     /// A given latitude or longitude is out of the acceptable range
     GeographicDegreesOutOfBounds,
+    /// A latitude or longitude string could not be parsed
+    UnparseableDegrees,
+    /// A grid reference is malformed or outside the British National Grid
+    InvalidGridReference,
+    /// A geohash is empty, too long or contains invalid characters
+    InvalidGeohash,
+    /// A Maidenhead locator is malformed
+    InvalidMaidenheadLocator,
     /// Significant forecast code does not match a known value
     UnknownWeatherCondition(i8),
+    /// The requested time is not covered by the forecast's predictions
+    TimeOutsideForecast(jiff::Timestamp),
+    /// Forecasts that were expected to be for the same site are for different sites
+    DifferentForecastSites,
+    /// An alert rule names a field that is not present in the forecast's predictions
+    UnknownField(alloc::string::String),
 }
 
 impl core::fmt::Display for Error {
@@ -14,7 +28,14 @@ impl core::fmt::Display for Error {
         let s: &'static str = match self {
             Error::Serde(_) => "JSON parsing error",
             Error::GeographicDegreesOutOfBounds => "invalid geographic degrees",
+            Error::UnparseableDegrees => "unparseable geographic degrees",
+            Error::InvalidGridReference => "invalid National Grid reference",
+            Error::InvalidGeohash => "invalid geohash",
+            Error::InvalidMaidenheadLocator => "invalid Maidenhead locator",
             Error::UnknownWeatherCondition(_) => "unknown significant weather code",
+            Error::TimeOutsideForecast(_) => "time outside forecast range",
+            Error::DifferentForecastSites => "forecasts are for different sites",
+            Error::UnknownField(_) => "unknown prediction field",
         };
         write!(f, "{s}")
     }
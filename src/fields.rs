@@ -0,0 +1,373 @@
+//! Flat, named view of prediction values, shared by the exporters
+use alloc::vec::Vec;
+
+use crate::daily::{Day, Night, TemperaturePrediction};
+use crate::units::{
+    Celsius, Conditions, Degrees, Metres, MetresPerSecond, Millimetres, MillimetresPerHour,
+    Pascals, Percentage, UvIndex, Visibility,
+};
+use crate::{Daily, Hourly, ThreeHourly, TimePeriod};
+
+/// Physical unit of a prediction field value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Unit {
+    Celsius,
+    MetresPerSecond,
+    Degrees,
+    Pascals,
+    Metres,
+    Millimetres,
+    MillimetresPerHour,
+    Percent,
+    /// Unitless index, such as the UV index
+    Index,
+    /// Met Office significant weather code
+    Code,
+}
+
+/// A prediction value in its own unit type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Value {
+    Temperature(Celsius),
+    Speed(MetresPerSecond),
+    Direction(Degrees),
+    Pressure(Pascals),
+    Distance(Metres),
+    Precipitation(Millimetres),
+    PrecipitationRate(MillimetresPerHour),
+    Percentage(Percentage),
+    UvIndex(UvIndex),
+    Conditions(Conditions),
+}
+
+impl Value {
+    /// The value as a plain number in the unit given by [`Value::unit`]
+    ///
+    /// Conditions are given as their significant weather code.
+    pub fn as_f32(&self) -> f32 {
+        match self {
+            Value::Temperature(t) => t.0,
+            Value::Speed(s) => s.0,
+            Value::Direction(d) => d.0,
+            Value::Pressure(p) => p.0 as f32,
+            Value::Distance(d) => d.0,
+            Value::Precipitation(p) => p.0,
+            Value::PrecipitationRate(r) => r.0,
+            Value::Percentage(p) => p.0,
+            Value::UvIndex(i) => i.0.into(),
+            Value::Conditions(c) => c.to_code().into(),
+        }
+    }
+
+    pub fn unit(&self) -> Unit {
+        match self {
+            Value::Temperature(_) => Unit::Celsius,
+            Value::Speed(_) => Unit::MetresPerSecond,
+            Value::Direction(_) => Unit::Degrees,
+            Value::Pressure(_) => Unit::Pascals,
+            Value::Distance(_) => Unit::Metres,
+            Value::Precipitation(_) => Unit::Millimetres,
+            Value::PrecipitationRate(_) => Unit::MillimetresPerHour,
+            Value::Percentage(_) => Unit::Percent,
+            Value::UvIndex(_) => Unit::Index,
+            Value::Conditions(_) => Unit::Code,
+        }
+    }
+}
+
+macro_rules! value_from {
+    ($($variant:ident($unit:ty)),* $(,)?) => {
+        $(
+            impl From<$unit> for Value {
+                fn from(value: $unit) -> Self {
+                    Value::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+value_from!(
+    Temperature(Celsius),
+    Speed(MetresPerSecond),
+    Direction(Degrees),
+    Pressure(Pascals),
+    Distance(Metres),
+    Precipitation(Millimetres),
+    PrecipitationRate(MillimetresPerHour),
+    Percentage(Percentage),
+    UvIndex(UvIndex),
+    Conditions(Conditions),
+);
+
+impl From<Visibility> for Value {
+    fn from(value: Visibility) -> Self {
+        Value::Distance(value.into())
+    }
+}
+
+impl<T: Copy + Into<Value>> From<&T> for Value {
+    fn from(value: &T) -> Self {
+        (*value).into()
+    }
+}
+
+/// A single named prediction value
+///
+/// Names are the struct field names used by this crate, not the Met Office API names.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Field {
+    pub name: &'static str,
+    pub value: Value,
+}
+
+impl Field {
+    fn new(name: &'static str, value: impl Into<Value>) -> Self {
+        Self {
+            name,
+            value: value.into(),
+        }
+    }
+}
+
+/// Prediction types that can be flattened into a list of named fields
+///
+/// Fields that are missing from a particular prediction are omitted rather than
+/// given a placeholder value.
+pub(crate) trait Fields: TimePeriod {
+    /// All fields present in the prediction, in a stable order
+    fn fields(&self) -> Vec<Field>;
+}
+
+impl Fields for Hourly {
+    fn fields(&self) -> Vec<Field> {
+        [
+            Some(Field::new("conditions", self.conditions)),
+            Some(Field::new("temperature", self.temperature)),
+            self.temperature_maximum
+                .map(|t| Field::new("temperature_maximum", t)),
+            self.temperature_minimum
+                .map(|t| Field::new("temperature_minimum", t)),
+            Some(Field::new(
+                "temperature_feels_like",
+                self.temperature_feels_like,
+            )),
+            Some(Field::new(
+                "dew_point_temperature",
+                self.dew_point_temperature,
+            )),
+            Some(Field::new(
+                "precipitation_probability",
+                self.precipitation_probability,
+            )),
+            Some(Field::new("precipitation_rate", self.precipitation_rate)),
+            self.precipitation_total
+                .map(|p| Field::new("precipitation_total", p)),
+            self.snow_total.map(|s| Field::new("snow_total", s)),
+            Some(Field::new("wind_speed", self.wind_speed)),
+            Some(Field::new("wind_direction", self.wind_direction)),
+            Some(Field::new("wind_gust_speed", self.wind_gust_speed)),
+            self.wind_gust_hourly_maximum_speed
+                .map(|g| Field::new("wind_gust_hourly_maximum_speed", g)),
+            Some(Field::new("visibility", self.visibility)),
+            Some(Field::new("relative_humidity", self.relative_humidity)),
+            Some(Field::new("pressure", self.pressure)),
+            Some(Field::new("uv_index", self.uv_index)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Fields for ThreeHourly {
+    fn fields(&self) -> Vec<Field> {
+        alloc::vec![
+            Field::new("conditions", self.conditions),
+            Field::new("temperature_maximum", self.temperature_maximum),
+            Field::new("temperature_minimum", self.temperature_minimum),
+            Field::new("temperature_feels_like", self.temperature_feels_like),
+            Field::new("precipitation_probability", self.precipitation_probability),
+            Field::new("precipitation_total", self.precipitation_total),
+            Field::new("snow_total", self.snow_total),
+            Field::new("rain_probability", self.rain_probability),
+            Field::new("heavy_rain_probability", self.heavy_rain_probability),
+            Field::new("snow_probability", self.snow_probability),
+            Field::new("heavy_snow_probability", self.heavy_snow_probability),
+            Field::new("hail_probability", self.hail_probability),
+            Field::new("lightning_probability", self.lightning_probability),
+            Field::new("wind_speed", self.wind_speed),
+            Field::new("wind_direction", self.wind_direction),
+            Field::new("wind_gust_speed", self.wind_gust_speed),
+            Field::new(
+                "wind_gust_three_hourly_maximum",
+                self.wind_gust_three_hourly_maximum
+            ),
+            Field::new("visibility", self.visibility),
+            Field::new("relative_humidity", self.relative_humidity),
+            Field::new("pressure", self.pressure),
+            Field::new("uv_index", self.uv_index),
+        ]
+    }
+}
+
+impl Fields for Daily {
+    /// Day and night fields flattened into one list, prefixed with `day_` and `night_`
+    fn fields(&self) -> Vec<Field> {
+        let mut fields = Vec::new();
+        push_day_fields(&mut fields, &self.day);
+        push_night_fields(&mut fields, &self.night);
+        fields
+    }
+}
+
+/// Push the most likely value and bounds of a temperature prediction
+fn push_temperature(
+    fields: &mut Vec<Field>,
+    [most_likely, upper_bound, lower_bound]: [&'static str; 3],
+    t: &TemperaturePrediction,
+) {
+    fields.push(Field::new(most_likely, t.most_likely));
+    fields.push(Field::new(upper_bound, t.upper_bound));
+    fields.push(Field::new(lower_bound, t.lower_bound));
+}
+
+fn push_day_fields(fields: &mut Vec<Field>, day: &Day) {
+    match day {
+        Day::Past {
+            temperature_maximum,
+            temperature_feels_like_maximum_upper_bound,
+            temperature_feels_like_maximum_lower_bound,
+            ..
+        } => {
+            push_temperature(
+                fields,
+                [
+                    "day_temperature_maximum",
+                    "day_temperature_maximum_upper_bound",
+                    "day_temperature_maximum_lower_bound",
+                ],
+                temperature_maximum,
+            );
+            fields.push(Field::new(
+                "day_temperature_feels_like_maximum_upper_bound",
+                temperature_feels_like_maximum_upper_bound,
+            ));
+            fields.push(Field::new(
+                "day_temperature_feels_like_maximum_lower_bound",
+                temperature_feels_like_maximum_lower_bound,
+            ));
+        }
+        Day::Future {
+            conditions,
+            temperature_maximum,
+            temperature_feels_like_maximum,
+            precipitation_probability,
+            rain_probability,
+            heavy_rain_probability,
+            snow_probability,
+            heavy_snow_probability,
+            hail_probability,
+            lightning_probability,
+            uv_index_maximum,
+            ..
+        } => {
+            fields.push(Field::new("day_conditions", conditions));
+            push_temperature(
+                fields,
+                [
+                    "day_temperature_maximum",
+                    "day_temperature_maximum_upper_bound",
+                    "day_temperature_maximum_lower_bound",
+                ],
+                temperature_maximum,
+            );
+            push_temperature(
+                fields,
+                [
+                    "day_temperature_feels_like_maximum",
+                    "day_temperature_feels_like_maximum_upper_bound",
+                    "day_temperature_feels_like_maximum_lower_bound",
+                ],
+                temperature_feels_like_maximum,
+            );
+            fields.extend([
+                Field::new("day_precipitation_probability", precipitation_probability),
+                Field::new("day_rain_probability", rain_probability),
+                Field::new("day_heavy_rain_probability", heavy_rain_probability),
+                Field::new("day_snow_probability", snow_probability),
+                Field::new("day_heavy_snow_probability", heavy_snow_probability),
+                Field::new("day_hail_probability", hail_probability),
+                Field::new("day_lightning_probability", lightning_probability),
+                Field::new("day_uv_index_maximum", uv_index_maximum),
+            ]);
+        }
+    }
+    // Midday values are present for both past and future days.
+    let (Day::Past {
+        relative_humidity,
+        pressure,
+        visibility,
+        wind_speed,
+        wind_direction,
+        wind_gust_speed,
+        ..
+    }
+    | Day::Future {
+        relative_humidity,
+        pressure,
+        visibility,
+        wind_speed,
+        wind_direction,
+        wind_gust_speed,
+        ..
+    }) = day;
+    fields.extend([
+        Field::new("day_relative_humidity", relative_humidity),
+        Field::new("day_pressure", pressure),
+        Field::new("day_visibility", visibility),
+        Field::new("day_wind_speed", wind_speed),
+        Field::new("day_wind_direction", wind_direction),
+        Field::new("day_wind_gust_speed", wind_gust_speed),
+    ]);
+}
+
+fn push_night_fields(fields: &mut Vec<Field>, night: &Night) {
+    fields.push(Field::new("night_conditions", night.conditions));
+    push_temperature(
+        fields,
+        [
+            "night_temperature_minimum",
+            "night_temperature_minimum_upper_bound",
+            "night_temperature_minimum_lower_bound",
+        ],
+        &night.temperature_minimum,
+    );
+    push_temperature(
+        fields,
+        [
+            "night_temperature_feels_like_minimum",
+            "night_temperature_feels_like_minimum_upper_bound",
+            "night_temperature_feels_like_minimum_lower_bound",
+        ],
+        &night.temperature_feels_like_minimum,
+    );
+    fields.extend([
+        Field::new(
+            "night_precipitation_probability",
+            night.precipitation_probability,
+        ),
+        Field::new("night_rain_probability", night.rain_probability),
+        Field::new("night_heavy_rain_probability", night.heavy_rain_probability),
+        Field::new("night_snow_probability", night.snow_probability),
+        Field::new("night_heavy_snow_probability", night.heavy_snow_probability),
+        Field::new("night_hail_probability", night.hail_probability),
+        Field::new("night_lightning_probability", night.lightning_probability),
+        Field::new("night_relative_humidity", night.relative_humidity),
+        Field::new("night_pressure", night.pressure),
+        Field::new("night_visibility", night.visibility),
+        Field::new("night_wind_speed", night.wind_speed),
+        Field::new("night_wind_direction", night.wind_direction),
+        Field::new("night_wind_gust_speed", night.wind_gust_speed),
+    ]);
+}
@@ -0,0 +1,100 @@
+//! Formatting predictions in a chosen unit system
+//!
+//! The `Display` impls in [`units`] always use the Met Office's own units. To show predictions in
+//! other units, choose a [`UnitSystem`] once and format values or whole predictions with it:
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! use rjw_metoffice::units::UnitSystem;
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let hour = &forecast.predictions[0];
+//! assert_eq!(UnitSystem::US.format_temperature(hour.temperature), "63.8°F");
+//! assert_eq!(UnitSystem::UK.format_temperature(hour.temperature), "17.6°C");
+//! assert_eq!(UnitSystem::US.format_pressure(hour.pressure), "29.94 inHg");
+//!
+//! let formatted = hour.formatted(&UnitSystem::US);
+//! assert!(formatted.to_string().contains("\ntemperature: 63.8°F\n"));
+//! ```
+//!
+//! [`Formatted`] formats every field of a prediction, using the same field names as the
+//! exporters (so daily fields are prefixed with `day_` and `night_`). Its [`Display`] impl writes
+//! one `name: value` line per field.
+//!
+//! [`units`]: crate::units
+//! [`Display`]: core::fmt::Display
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::fields::{Field, Fields, Value};
+use crate::units::UnitSystem;
+use crate::{Daily, Hourly, ThreeHourly};
+
+/// A prediction formatted in a particular [`UnitSystem`]
+#[derive(Debug, Clone)]
+pub struct Formatted {
+    fields: Vec<Field>,
+    units: UnitSystem,
+}
+
+impl Formatted {
+    fn new(prediction: &impl Fields, units: &UnitSystem) -> Self {
+        Self {
+            fields: prediction.fields(),
+            units: *units,
+        }
+    }
+
+    /// Names and formatted values of every field present in the prediction
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, String)> + '_ {
+        self.fields
+            .iter()
+            .map(|field| (field.name, self.format(field.value)))
+    }
+
+    fn format(&self, value: Value) -> String {
+        match value {
+            Value::Temperature(t) => self.units.format_temperature(t),
+            Value::Speed(s) => self.units.format_speed(s),
+            Value::Direction(d) => d.to_string(),
+            Value::Pressure(p) => self.units.format_pressure(p),
+            Value::Distance(d) => self.units.format_distance(d),
+            Value::Precipitation(p) => self.units.format_precipitation(p),
+            Value::PrecipitationRate(r) => self.units.format_precipitation_rate(r),
+            Value::Percentage(p) => p.to_string(),
+            Value::UvIndex(i) => i.to_string(),
+            Value::Conditions(c) => c.to_string(),
+        }
+    }
+}
+
+impl core::fmt::Display for Formatted {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (name, value) in self.iter() {
+            writeln!(f, "{name}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Hourly {
+    /// Format every field of this prediction in the given unit system
+    pub fn formatted(&self, units: &UnitSystem) -> Formatted {
+        Formatted::new(self, units)
+    }
+}
+
+impl ThreeHourly {
+    /// Format every field of this prediction in the given unit system
+    pub fn formatted(&self, units: &UnitSystem) -> Formatted {
+        Formatted::new(self, units)
+    }
+}
+
+impl Daily {
+    /// Format every field of this prediction in the given unit system
+    ///
+    /// Day and night fields are prefixed with `day_` and `night_`.
+    pub fn formatted(&self, units: &UnitSystem) -> Formatted {
+        Formatted::new(self, units)
+    }
+}
@@ -0,0 +1,838 @@
+//! Alternative coordinate formats
+//!
+//! Forecast locations are given in decimal degrees in WGS 84, but positions are often recorded
+//! in other ways. This module converts between [`Latitude`]/[`Longitude`] and:
+//!
+//! - Ordnance Survey National Grid references, such as "SX 925 925" ([`GridReference`])
+//! - Degrees, minutes and seconds, such as `50°43'12"N` (via `str::parse` and [`Dms`])
+//! - Geohashes, such as "gcj2" ([`Geohash`])
+//! - Maidenhead locators, such as "IO80fr" ([`Maidenhead`])
+//!
+//! Latitudes, longitudes and [`Coordinates`] can also be formatted in a chosen [`Notation`],
+//! including ISO 6709, with [`CoordinateFormat`].
+//!
+//! ```
+//! use rjw_metoffice::geo::GridReference;
+//! use rjw_metoffice::units::{Latitude, Longitude};
+//!
+//! let grid_ref: GridReference = "SX 925 925".parse().unwrap();
+//! let (latitude, longitude) = grid_ref.to_lat_lon();
+//! assert_eq!(latitude.to_string(), "50.722° N");
+//!
+//! let latitude: Latitude = "50°43'12\"N".parse().unwrap();
+//! let longitude: Longitude = "3° 31' 48\" W".parse().unwrap();
+//! let grid_ref = GridReference::from_lat_lon(latitude, longitude).unwrap();
+//! assert_eq!(format!("{grid_ref:.3}"), "SX 920 922");
+//! assert_eq!(format!("{:.1}", latitude.dms()), "50°43'12.0\"N");
+//! ```
+use alloc::format;
+use alloc::string::String;
+use core::str::FromStr;
+
+use crate::Error;
+use crate::units::{Coordinates, Latitude, Longitude};
+
+/// Reference ellipsoid, with semi-major and semi-minor axes in metres
+#[derive(Clone, Copy)]
+struct Ellipsoid {
+    a: f64,
+    b: f64,
+}
+
+/// Ellipsoid of the WGS 84 coordinate reference system
+const WGS84: Ellipsoid = Ellipsoid {
+    a: 6_378_137.0,
+    b: 6_356_752.314_245,
+};
+
+/// Airy 1830 ellipsoid, used by the OSGB36 datum of the National Grid
+const AIRY_1830: Ellipsoid = Ellipsoid {
+    a: 6_377_563.396,
+    b: 6_356_256.909,
+};
+
+impl Ellipsoid {
+    /// Eccentricity squared
+    fn e2(&self) -> f64 {
+        1.0 - (self.b * self.b) / (self.a * self.a)
+    }
+
+    /// Earth-centred cartesian coordinates of a point on the ellipsoid surface
+    fn to_cartesian(self, phi: f64, lambda: f64) -> [f64; 3] {
+        let e2 = self.e2();
+        let sin_phi = libm::sin(phi);
+        let nu = self.a / libm::sqrt(1.0 - e2 * sin_phi * sin_phi);
+        [
+            nu * libm::cos(phi) * libm::cos(lambda),
+            nu * libm::cos(phi) * libm::sin(lambda),
+            (1.0 - e2) * nu * sin_phi,
+        ]
+    }
+
+    /// Latitude and longitude in radians of cartesian coordinates, discarding height
+    fn to_geodetic(self, [x, y, z]: [f64; 3]) -> (f64, f64) {
+        let e2 = self.e2();
+        let p = libm::hypot(x, y);
+        let mut phi = libm::atan2(z, p * (1.0 - e2));
+        for _ in 0..10 {
+            let sin_phi = libm::sin(phi);
+            let nu = self.a / libm::sqrt(1.0 - e2 * sin_phi * sin_phi);
+            phi = libm::atan2(z + e2 * nu * sin_phi, p);
+        }
+        (phi, libm::atan2(y, x))
+    }
+}
+
+/// Seven-parameter Helmert transformation between datums
+///
+/// Translations are in metres, the scale in parts per million and rotations in arcseconds.
+#[derive(Clone, Copy)]
+struct Helmert {
+    tx: f64,
+    ty: f64,
+    tz: f64,
+    s: f64,
+    rx: f64,
+    ry: f64,
+    rz: f64,
+}
+
+/// WGS 84 to OSGB36, from the Ordnance Survey's "A Guide to Coordinate Systems in Great
+/// Britain", accurate to a few metres
+const WGS84_TO_OSGB36: Helmert = Helmert {
+    tx: -446.448,
+    ty: 125.157,
+    tz: -542.060,
+    s: 20.4894,
+    rx: -0.1502,
+    ry: -0.2470,
+    rz: -0.8421,
+};
+
+impl Helmert {
+    /// Approximate reverse transformation
+    fn inverse(self) -> Helmert {
+        Helmert {
+            tx: -self.tx,
+            ty: -self.ty,
+            tz: -self.tz,
+            s: -self.s,
+            rx: -self.rx,
+            ry: -self.ry,
+            rz: -self.rz,
+        }
+    }
+
+    fn apply(self, [x, y, z]: [f64; 3]) -> [f64; 3] {
+        let arcsec = (1.0_f64 / 3600.0).to_radians();
+        let (rx, ry, rz) = (self.rx * arcsec, self.ry * arcsec, self.rz * arcsec);
+        let s = 1.0 + self.s * 1e-6;
+        [
+            self.tx + s * x - rz * y + ry * z,
+            self.ty + rz * x + s * y - rx * z,
+            self.tz - ry * x + rx * y + s * z,
+        ]
+    }
+}
+
+/// National Grid scale factor on the central meridian
+const F0: f64 = 0.999_601_271_7;
+/// Latitude of the National Grid true origin, 49°N
+const PHI0: f64 = 49.0 * core::f64::consts::PI / 180.0;
+/// Longitude of the National Grid true origin, 2°W
+const LAMBDA0: f64 = -2.0 * core::f64::consts::PI / 180.0;
+/// Easting of the true origin
+const E0: f64 = 400_000.0;
+/// Northing of the true origin
+const N0: f64 = -100_000.0;
+
+/// Meridional arc from the true origin's latitude to `phi`, scaled by `F0`
+fn meridional_arc(phi: f64) -> f64 {
+    let Ellipsoid { a, b } = AIRY_1830;
+    let n = (a - b) / (a + b);
+    let (n2, n3) = (n * n, n * n * n);
+    let (d, s) = (phi - PHI0, phi + PHI0);
+    b * F0
+        * ((1.0 + n + 1.25 * n2 + 1.25 * n3) * d
+            - (3.0 * n + 3.0 * n2 + 2.625 * n3) * libm::sin(d) * libm::cos(s)
+            + (1.875 * n2 + 1.875 * n3) * libm::sin(2.0 * d) * libm::cos(2.0 * s)
+            - (35.0 / 24.0) * n3 * libm::sin(3.0 * d) * libm::cos(3.0 * s))
+}
+
+/// Radii of curvature ν and ρ (scaled by `F0`) and η² at latitude `phi`
+fn curvature(phi: f64) -> (f64, f64, f64) {
+    let a = AIRY_1830.a;
+    let e2 = AIRY_1830.e2();
+    let sin2 = libm::sin(phi) * libm::sin(phi);
+    let nu = a * F0 / libm::sqrt(1.0 - e2 * sin2);
+    let rho = a * F0 * (1.0 - e2) / libm::pow(1.0 - e2 * sin2, 1.5);
+    (nu, rho, nu / rho - 1.0)
+}
+
+/// Transverse Mercator projection of OSGB36 latitude and longitude to easting and northing
+fn project(phi: f64, lambda: f64) -> (f64, f64) {
+    let (nu, rho, eta2) = curvature(phi);
+    let (sin, cos, tan) = (libm::sin(phi), libm::cos(phi), libm::tan(phi));
+    let (cos3, tan2) = (cos * cos * cos, tan * tan);
+    let (cos5, tan4) = (cos3 * cos * cos, tan2 * tan2);
+
+    let i = meridional_arc(phi) + N0;
+    let ii = nu / 2.0 * sin * cos;
+    let iii = nu / 24.0 * sin * cos3 * (5.0 - tan2 + 9.0 * eta2);
+    let iiia = nu / 720.0 * sin * cos5 * (61.0 - 58.0 * tan2 + tan4);
+    let iv = nu * cos;
+    let v = nu / 6.0 * cos3 * (nu / rho - tan2);
+    let vi = nu / 120.0 * cos5 * (5.0 - 18.0 * tan2 + tan4 + 14.0 * eta2 - 58.0 * tan2 * eta2);
+
+    let dl = lambda - LAMBDA0;
+    let (dl2, dl3) = (dl * dl, dl * dl * dl);
+    let northing = i + ii * dl2 + iii * dl2 * dl2 + iiia * dl3 * dl3;
+    let easting = E0 + iv * dl + v * dl3 + vi * dl3 * dl2;
+    (easting, northing)
+}
+
+/// Inverse of [`project`], giving OSGB36 latitude and longitude in radians
+fn unproject(easting: f64, northing: f64) -> (f64, f64) {
+    let a = AIRY_1830.a;
+    let mut phi = PHI0;
+    let mut m = 0.0;
+    loop {
+        phi += (northing - N0 - m) / (a * F0);
+        m = meridional_arc(phi);
+        if libm::fabs(northing - N0 - m) < 1e-5 {
+            break;
+        }
+    }
+
+    let (nu, rho, eta2) = curvature(phi);
+    let tan = libm::tan(phi);
+    let (tan2, sec) = (tan * tan, 1.0 / libm::cos(phi));
+    let (tan4, tan6) = (tan2 * tan2, tan2 * tan2 * tan2);
+    let nu3 = nu * nu * nu;
+    let (nu5, nu7) = (nu3 * nu * nu, nu3 * nu3 * nu);
+
+    let vii = tan / (2.0 * rho * nu);
+    let viii = tan / (24.0 * rho * nu3) * (5.0 + 3.0 * tan2 + eta2 - 9.0 * tan2 * eta2);
+    let ix = tan / (720.0 * rho * nu5) * (61.0 + 90.0 * tan2 + 45.0 * tan4);
+    let x = sec / nu;
+    let xi = sec / (6.0 * nu3) * (nu / rho + 2.0 * tan2);
+    let xii = sec / (120.0 * nu5) * (5.0 + 28.0 * tan2 + 24.0 * tan4);
+    let xiia = sec / (5040.0 * nu7) * (61.0 + 662.0 * tan2 + 1320.0 * tan4 + 720.0 * tan6);
+
+    let de = easting - E0;
+    let (de2, de3) = (de * de, de * de * de);
+    let phi = phi - vii * de2 + viii * de2 * de2 - ix * de3 * de3;
+    let lambda = LAMBDA0 + x * de - xi * de3 + xii * de3 * de2 - xiia * de3 * de3 * de;
+    (phi, lambda)
+}
+
+/// Ordnance Survey National Grid reference
+///
+/// A position on the British National Grid, as an easting and northing in metres from the
+/// grid's false origin south-west of the Isles of Scilly. Grid references are parsed from and
+/// displayed in the usual letter-pair form, such as "SX 925 925". The precision of the
+/// formatter gives the number of digits for each of the easting and northing (default 5, for
+/// 1-metre resolution):
+///
+/// ```
+/// # use rjw_metoffice::geo::GridReference;
+/// let grid_ref = GridReference::new(292_512.0, 92_538.0).unwrap();
+/// assert_eq!(grid_ref.to_string(), "SX 92512 92538");
+/// assert_eq!(format!("{grid_ref:.2}"), "SX 92 92");
+/// ```
+///
+/// As in Ordnance Survey usage, a shortened reference identifies the south-west corner of a
+/// square, so digits are truncated rather than rounded. Conversion to and from WGS 84 uses the
+/// Ordnance Survey's Helmert transformation, which is accurate to around 5 metres.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridReference {
+    easting: f64,
+    northing: f64,
+}
+
+impl GridReference {
+    /// Construct a grid reference from an easting and northing in metres
+    ///
+    /// Returns an error if the position is outside the National Grid (0–700 km east and
+    /// 0–1300 km north).
+    pub fn new(easting: f64, northing: f64) -> Result<Self, Error> {
+        if (0.0..700_000.0).contains(&easting) && (0.0..1_300_000.0).contains(&northing) {
+            Ok(Self { easting, northing })
+        } else {
+            Err(Error::InvalidGridReference)
+        }
+    }
+
+    /// Grid reference of a WGS 84 position
+    ///
+    /// Returns an error if the position is outside the National Grid.
+    pub fn from_lat_lon(latitude: Latitude, longitude: Longitude) -> Result<Self, Error> {
+        let wgs84 = WGS84.to_cartesian(
+            latitude.as_float().to_radians(),
+            longitude.as_float().to_radians(),
+        );
+        let (phi, lambda) = AIRY_1830.to_geodetic(WGS84_TO_OSGB36.apply(wgs84));
+        let (easting, northing) = project(phi, lambda);
+        Self::new(easting, northing)
+    }
+
+    /// WGS 84 position of this grid reference
+    pub fn to_lat_lon(&self) -> (Latitude, Longitude) {
+        let (phi, lambda) = unproject(self.easting, self.northing);
+        let osgb36 = AIRY_1830.to_cartesian(phi, lambda);
+        let (phi, lambda) = WGS84.to_geodetic(WGS84_TO_OSGB36.inverse().apply(osgb36));
+        (Latitude(phi.to_degrees()), Longitude(lambda.to_degrees()))
+    }
+
+    /// Distance east of the false origin, in metres
+    pub fn easting(&self) -> f64 {
+        self.easting
+    }
+
+    /// Distance north of the false origin, in metres
+    pub fn northing(&self) -> f64 {
+        self.northing
+    }
+}
+
+/// Index of a grid letter in the 5×5 lettering of a square, which omits 'I'
+fn grid_letter_index(c: char) -> Option<u32> {
+    match c.to_ascii_uppercase() {
+        'I' => None,
+        c @ 'A'..='H' => Some(c as u32 - 'A' as u32),
+        c @ 'J'..='Z' => Some(c as u32 - 'A' as u32 - 1),
+        _ => None,
+    }
+}
+
+/// Grid letter at an index in the 5×5 lettering of a square
+fn grid_letter(i: u32) -> char {
+    let i = if i > 7 { i + 1 } else { i };
+    char::from(b'A' + i as u8)
+}
+
+impl FromStr for GridReference {
+    type Err = Error;
+
+    /// Parse a grid reference such as "SX 925 925", "sx925925" or "SX 92512 92538"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || Error::InvalidGridReference;
+        let s = s.trim();
+        let mut chars = s.chars();
+        let l1 = chars.next().and_then(grid_letter_index).ok_or_else(err)?;
+        let l2 = chars.next().and_then(grid_letter_index).ok_or_else(err)?;
+        let e100k = (l1 as i32 - 2).rem_euclid(5) * 5 + (l2 % 5) as i32;
+        let n100k = 19 - (l1 as i32 / 5) * 5 - (l2 / 5) as i32;
+
+        let rest = chars.as_str();
+        let mut parts = rest.split_whitespace();
+        let (e, n) = match (parts.next(), parts.next(), parts.next()) {
+            (None, _, _) => ("", ""),
+            (Some(both), None, _) if both.len().is_multiple_of(2) => both.split_at(both.len() / 2),
+            (Some(e), Some(n), None) if e.len() == n.len() => (e, n),
+            _ => return Err(err()),
+        };
+        let digits = |d: &str| -> Result<f64, Error> {
+            if d.len() > 5 || !d.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(err());
+            }
+            let value: u32 = if d.is_empty() {
+                0
+            } else {
+                d.parse().map_err(|_| err())?
+            };
+            Ok(f64::from(value * 10u32.pow(5 - d.len() as u32)))
+        };
+
+        Self::new(
+            f64::from(e100k * 100_000) + digits(e)?,
+            f64::from(n100k * 100_000) + digits(n)?,
+        )
+    }
+}
+
+impl core::fmt::Display for GridReference {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let digits = f.precision().unwrap_or(5).min(5);
+        let (e, n) = (self.easting as u32, self.northing as u32);
+        let (e100k, n100k) = (e / 100_000, n / 100_000);
+        let l1 = (19 - n100k) - (19 - n100k) % 5 + (e100k + 10) / 5;
+        let l2 = (19 - n100k) * 5 % 25 + e100k % 5;
+        write!(f, "{}{}", grid_letter(l1), grid_letter(l2))?;
+        if digits > 0 {
+            let scale = 10u32.pow(5 - digits as u32);
+            let (e, n) = (e % 100_000 / scale, n % 100_000 / scale);
+            write!(f, " {e:0digits$} {n:0digits$}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse decimal degrees or degrees, minutes and seconds with an optional hemisphere letter
+///
+/// `positive` and `negative` are the hemisphere letters for each sign, such as 'N' and 'S'.
+fn parse_degrees(s: &str, positive: char, negative: char) -> Result<f64, Error> {
+    let err = || Error::UnparseableDegrees;
+    let is_hemisphere = |c: &char| {
+        let c = c.to_ascii_uppercase();
+        c == positive || c == negative
+    };
+    let mut s = s.trim();
+    let mut hemisphere = None;
+    if let Some(c) = s.chars().next().filter(is_hemisphere) {
+        hemisphere = Some(c);
+        s = &s[c.len_utf8()..];
+    } else if let Some(c) = s.chars().next_back().filter(is_hemisphere) {
+        hemisphere = Some(c);
+        s = &s[..s.len() - c.len_utf8()];
+    }
+
+    if s.chars().any(char::is_alphabetic) {
+        return Err(err());
+    }
+    let mut parts = s
+        .split(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .filter(|p| !p.is_empty());
+    let mut next = || -> Result<Option<f64>, Error> {
+        parts
+            .next()
+            .map(|p| p.parse().map_err(|_| err()))
+            .transpose()
+    };
+    let degrees = next()?.ok_or_else(err)?;
+    let minutes = next()?;
+    let seconds = next()?;
+    if next()?.is_some() {
+        return Err(err());
+    }
+
+    // Only the last component may have a fractional part, and only the degrees a sign.
+    let whole = |x: f64| x == libm::trunc(x);
+    let in_range = |x: Option<f64>| x.is_none_or(|x| (0.0..60.0).contains(&x));
+    if (minutes.is_some() && !whole(degrees))
+        || (seconds.is_some() && !minutes.is_some_and(whole))
+        || !in_range(minutes)
+        || !in_range(seconds)
+        || (hemisphere.is_some() && degrees.is_sign_negative())
+    {
+        return Err(err());
+    }
+
+    let magnitude =
+        libm::fabs(degrees) + minutes.unwrap_or(0.0) / 60.0 + seconds.unwrap_or(0.0) / 3600.0;
+    let negative = match hemisphere {
+        Some(c) => c.to_ascii_uppercase() == negative,
+        None => degrees.is_sign_negative(),
+    };
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+impl FromStr for Latitude {
+    type Err = Error;
+
+    /// Parse a latitude in decimal degrees or degrees, minutes and seconds
+    ///
+    /// Accepts signed decimal degrees ("-12.5"), or degrees with optional minutes and seconds
+    /// and an optional leading or trailing 'N' or 'S' ("50°43'12.5\"N", "N 50 43.2"). The
+    /// components may be separated by whitespace, colons, or degree, minute and second symbols.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Latitude::new(parse_degrees(s, 'N', 'S')?)
+    }
+}
+
+impl FromStr for Longitude {
+    type Err = Error;
+
+    /// Parse a longitude in decimal degrees or degrees, minutes and seconds
+    ///
+    /// Accepts the same forms as [`Latitude`], with the hemisphere letters 'E' and 'W'.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Longitude::new(parse_degrees(s, 'E', 'W')?)
+    }
+}
+
+/// Latitude or longitude displayed in degrees, minutes and seconds
+///
+/// The precision of the formatter gives the number of decimal places of the seconds (default
+/// 0), such as `50°43'12"N` or `{:.2}` for `50°43'12.35"N`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dms {
+    degrees: f64,
+    hemisphere: char,
+}
+
+impl Latitude {
+    /// This latitude in degrees, minutes and seconds
+    pub fn dms(&self) -> Dms {
+        Dms::new(self.as_float(), 'N', 'S')
+    }
+
+    /// Format this latitude in the given notation
+    pub fn format(&self, format: &CoordinateFormat) -> String {
+        format_degrees(self.as_float(), format, 'N', 'S', 2)
+    }
+}
+
+impl Longitude {
+    /// This longitude in degrees, minutes and seconds
+    pub fn dms(&self) -> Dms {
+        Dms::new(self.as_float(), 'E', 'W')
+    }
+
+    /// Format this longitude in the given notation
+    pub fn format(&self, format: &CoordinateFormat) -> String {
+        format_degrees(self.as_float(), format, 'E', 'W', 3)
+    }
+}
+
+impl Dms {
+    fn new(degrees: f64, positive: char, negative: char) -> Self {
+        let hemisphere = if degrees.is_sign_negative() {
+            negative
+        } else {
+            positive
+        };
+        Dms {
+            degrees: libm::fabs(degrees),
+            hemisphere,
+        }
+    }
+}
+
+impl core::fmt::Display for Dms {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let p = f.precision().unwrap_or(0).min(9);
+        let scale = 10u64.pow(p as u32);
+        // Round once in the smallest unit, so that 59.96" carries into the minutes.
+        let total = libm::round(self.degrees * 3600.0 * scale as f64) as u64;
+        let degrees = total / (3600 * scale);
+        let minutes = total / (60 * scale) % 60;
+        let seconds = (total % (60 * scale)) as f64 / scale as f64;
+        let width = if p > 0 { p + 3 } else { 2 };
+        write!(
+            f,
+            "{degrees}°{minutes:02}'{seconds:0width$.p$}\"{}",
+            self.hemisphere
+        )
+    }
+}
+
+/// Notation for displaying latitudes and longitudes
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    /// Signed decimal degrees, such as "-3.474"
+    Decimal,
+    /// Decimal degrees with a hemisphere letter, such as "3.474° W", as used by `Display`
+    #[default]
+    Hemisphere,
+    /// Degrees, minutes and seconds with a hemisphere letter, such as `3°28'26"W`
+    Dms,
+    /// ISO 6709 string representation, such as "-003.474"
+    ///
+    /// Latitudes have two integer digits and longitudes three, both with an explicit sign.
+    /// Coordinates are written as latitude, longitude and optional altitude, followed by '/',
+    /// such as "+50.726-003.474+38CRSWGS_84/".
+    Iso6709,
+}
+
+/// Options for formatting latitudes, longitudes and coordinates
+///
+/// The default matches the `Display` impls: hemisphere letters, three decimal places, and
+/// coordinates with their altitude.
+///
+/// ```
+/// use rjw_metoffice::geo::{CoordinateFormat, Notation};
+/// use rjw_metoffice::units::Longitude;
+///
+/// let longitude = Longitude::new(-3.474).unwrap();
+/// let format = CoordinateFormat {
+///     notation: Notation::Decimal,
+///     precision: Some(1),
+///     ..Default::default()
+/// };
+/// assert_eq!(longitude.format(&format), "-3.5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoordinateFormat {
+    pub notation: Notation,
+    /// Decimal places of the degrees, or of the seconds in DMS notation
+    ///
+    /// Defaults to 3 decimal places of degrees (about 100 metres) or whole seconds.
+    pub precision: Option<usize>,
+    /// Whether to include the altitude when formatting [`Coordinates`]
+    pub altitude: bool,
+}
+
+impl Default for CoordinateFormat {
+    fn default() -> Self {
+        Self {
+            notation: Notation::default(),
+            precision: None,
+            altitude: true,
+        }
+    }
+}
+
+impl CoordinateFormat {
+    /// Default options for the given notation
+    pub fn new(notation: Notation) -> Self {
+        Self {
+            notation,
+            ..Default::default()
+        }
+    }
+}
+
+/// Format degrees of latitude or longitude
+///
+/// `integer_digits` is the zero-padded width of the whole degrees in ISO 6709 notation.
+fn format_degrees(
+    degrees: f64,
+    format: &CoordinateFormat,
+    positive: char,
+    negative: char,
+    integer_digits: usize,
+) -> String {
+    let p = format.precision;
+    match format.notation {
+        Notation::Decimal => format!("{:.*}", p.unwrap_or(3), degrees),
+        Notation::Hemisphere => {
+            let dms = Dms::new(degrees, positive, negative);
+            format!("{:.*}° {}", p.unwrap_or(3), dms.degrees, dms.hemisphere)
+        }
+        Notation::Dms => format!(
+            "{:.*}",
+            p.unwrap_or(0),
+            Dms::new(degrees, positive, negative)
+        ),
+        Notation::Iso6709 => {
+            let p = p.unwrap_or(3);
+            let width = 1 + integer_digits + if p > 0 { p + 1 } else { 0 };
+            format!("{degrees:+0width$.p$}")
+        }
+    }
+}
+
+impl Coordinates {
+    /// Format these coordinates in the given notation, with or without the altitude
+    ///
+    /// ```
+    /// # use rjw_metoffice::units::Coordinates;
+    /// use rjw_metoffice::geo::{CoordinateFormat, Notation};
+    ///
+    /// let exeter = Coordinates::try_from([-3.4739, 50.7264, 38.0]).unwrap();
+    /// assert_eq!(exeter.to_string(), "50.726° N, 3.474° W 38m");
+    ///
+    /// let iso = CoordinateFormat::new(Notation::Iso6709);
+    /// assert_eq!(exeter.format(&iso), "+50.726-003.474+38CRSWGS_84/");
+    /// let dms = CoordinateFormat {
+    ///     altitude: false,
+    ///     ..CoordinateFormat::new(Notation::Dms)
+    /// };
+    /// assert_eq!(exeter.format(&dms), "50°43'35\"N, 3°28'26\"W");
+    /// ```
+    pub fn format(&self, format: &CoordinateFormat) -> String {
+        let latitude = self.latitude.format(format);
+        let longitude = self.longitude.format(format);
+        match (format.notation, format.altitude) {
+            (Notation::Iso6709, false) => format!("{latitude}{longitude}/"),
+            (Notation::Iso6709, true) => {
+                format!("{latitude}{longitude}{:+.0}CRSWGS_84/", self.altitude.0)
+            }
+            (_, false) => format!("{latitude}, {longitude}"),
+            (_, true) => format!("{latitude}, {longitude} {}", self.altitude),
+        }
+    }
+}
+
+/// Geohash alphabet, in order of value
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Geohash of a rectangular cell
+///
+/// Each character of a geohash subdivides the cell of the previous characters, so a longer
+/// geohash is more precise: 5 characters identify a cell of roughly 5 km, and 9 characters of
+/// roughly 5 m. Geohashes of 1 to 12 characters are supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Geohash(String);
+
+impl Geohash {
+    /// Geohash of the given length for a position
+    ///
+    /// The length is clamped to between 1 and 12 characters.
+    pub fn from_lat_lon(latitude: Latitude, longitude: Longitude, length: usize) -> Self {
+        let (lat, lon) = (latitude.as_float(), longitude.as_float());
+        let mut lat_range = (-90.0, 90.0);
+        let mut lon_range = (-180.0, 180.0);
+        let mut hash = String::new();
+        let mut even = true;
+        for _ in 0..length.clamp(1, 12) {
+            let mut index = 0;
+            for _ in 0..5 {
+                let (value, range) = if even {
+                    (lon, &mut lon_range)
+                } else {
+                    (lat, &mut lat_range)
+                };
+                let mid = (range.0 + range.1) / 2.0;
+                index <<= 1;
+                if value >= mid {
+                    index |= 1;
+                    range.0 = mid;
+                } else {
+                    range.1 = mid;
+                }
+                even = !even;
+            }
+            hash.push(char::from(BASE32[index]));
+        }
+        Self(hash)
+    }
+
+    /// Position of the centre of the cell
+    pub fn to_lat_lon(&self) -> (Latitude, Longitude) {
+        let mut lat_range = (-90.0, 90.0);
+        let mut lon_range = (-180.0, 180.0);
+        let mut even = true;
+        for b in self.0.bytes() {
+            let index = BASE32.iter().position(|&c| c == b).unwrap_or_default();
+            for bit in (0..5).rev() {
+                let range = if even { &mut lon_range } else { &mut lat_range };
+                let mid = (range.0 + range.1) / 2.0;
+                if index >> bit & 1 == 1 {
+                    range.0 = mid;
+                } else {
+                    range.1 = mid;
+                }
+                even = !even;
+            }
+        }
+        (
+            Latitude((lat_range.0 + lat_range.1) / 2.0),
+            Longitude((lon_range.0 + lon_range.1) / 2.0),
+        )
+    }
+
+    /// The geohash characters
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Geohash {
+    type Err = Error;
+
+    /// Parse a geohash of 1 to 12 characters, ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hash = s.trim().to_ascii_lowercase();
+        if (1..=12).contains(&hash.len()) && hash.bytes().all(|b| BASE32.contains(&b)) {
+            Ok(Self(hash))
+        } else {
+            Err(Error::InvalidGeohash)
+        }
+    }
+}
+
+impl core::fmt::Display for Geohash {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Size in degrees of longitude of each pair of a Maidenhead locator (latitude is half)
+const MAIDENHEAD_SIZES: [f64; 4] = [20.0, 2.0, 2.0 / 24.0, 2.0 / 240.0];
+
+/// Number of divisions of each pair of a Maidenhead locator
+const MAIDENHEAD_DIVISIONS: [u32; 4] = [18, 10, 24, 10];
+
+/// First character of each pair of a Maidenhead locator
+fn maidenhead_base(pair: usize) -> u8 {
+    match pair {
+        0 => b'A',
+        2 => b'a',
+        _ => b'0',
+    }
+}
+
+/// Maidenhead locator, the grid square system used in amateur radio
+///
+/// A locator is made of up to four pairs, each subdividing the previous: a field ("IO", 20° by
+/// 10°), a square ("IO80", 2° by 1°), a subsquare ("IO80fr", 5′ by 2.5′), and an extended
+/// square ("IO80fr23", 30″ by 15″).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Maidenhead(String);
+
+impl Maidenhead {
+    /// Locator with the given number of pairs for a position
+    ///
+    /// The number of pairs is clamped to between 1 and 4.
+    pub fn from_lat_lon(latitude: Latitude, longitude: Longitude, pairs: usize) -> Self {
+        let mut lon = longitude.as_float() + 180.0;
+        let mut lat = latitude.as_float() + 90.0;
+        let mut locator = String::new();
+        for i in 0..pairs.clamp(1, 4) {
+            let (size, divisions) = (MAIDENHEAD_SIZES[i], MAIDENHEAD_DIVISIONS[i]);
+            let x = ((lon / size) as u32).min(divisions - 1);
+            let y = ((lat / (size / 2.0)) as u32).min(divisions - 1);
+            lon -= f64::from(x) * size;
+            lat -= f64::from(y) * size / 2.0;
+            let base = maidenhead_base(i);
+            locator.push(char::from(base + x as u8));
+            locator.push(char::from(base + y as u8));
+        }
+        Self(locator)
+    }
+
+    /// Position of the centre of the locator's square
+    pub fn to_lat_lon(&self) -> (Latitude, Longitude) {
+        let (mut lon, mut lat) = (-180.0, -90.0);
+        let bytes = self.0.as_bytes();
+        let mut size = 0.0;
+        for (i, pair) in bytes.chunks(2).enumerate() {
+            size = MAIDENHEAD_SIZES[i];
+            let base = maidenhead_base(i);
+            lon += f64::from(pair[0] - base) * size;
+            lat += f64::from(pair[1] - base) * size / 2.0;
+        }
+        (Latitude(lat + size / 4.0), Longitude(lon + size / 2.0))
+    }
+
+    /// The locator characters
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Maidenhead {
+    type Err = Error;
+
+    /// Parse a locator of 1 to 4 pairs, such as "IO80fr", ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if !(2..=8).contains(&s.len()) || !s.len().is_multiple_of(2) {
+            return Err(Error::InvalidMaidenheadLocator);
+        }
+        let mut locator = String::new();
+        for (i, pair) in s.as_bytes().chunks(2).enumerate() {
+            for &b in pair {
+                let b = match i {
+                    0 => b.to_ascii_uppercase(),
+                    2 => b.to_ascii_lowercase(),
+                    _ => b,
+                };
+                let base = maidenhead_base(i);
+                if !(base..base + MAIDENHEAD_DIVISIONS[i] as u8).contains(&b) {
+                    return Err(Error::InvalidMaidenheadLocator);
+                }
+                locator.push(char::from(b));
+            }
+        }
+        Ok(Self(locator))
+    }
+}
+
+impl core::fmt::Display for Maidenhead {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
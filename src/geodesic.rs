@@ -0,0 +1,206 @@
+//! Distances and bearings between positions
+//!
+//! Two methods are provided. The [haversine formula] treats the Earth as a sphere, which is
+//! simple and robust but can be out by up to 0.5%. [Vincenty's formulae] work on the WGS 84
+//! ellipsoid and are accurate to within a millimetre, but can fail to converge for nearly
+//! antipodal points. [`Coordinates::distance_to`] uses Vincenty's formulae and falls back to the
+//! haversine formula when they fail:
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! use rjw_metoffice::compass::CompassPoints;
+//! use rjw_metoffice::units::{Coordinates, Kilometres};
+//!
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let me = Coordinates::try_from([-3.5, 50.7, 0.0]).unwrap();
+//! let site = forecast.coordinates;
+//! let distance = Kilometres::from(me.distance_to(&site));
+//! let direction = me.bearing_to(&site).compass(CompassPoints::Eight);
+//! assert_eq!(format!("{distance:.0} {direction} of you"), "4 km NE of you");
+//! ```
+//!
+//! Bearings are clockwise from true north, in the range [0°, 360°).
+//!
+//! [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+//! [Vincenty's formulae]: https://en.wikipedia.org/wiki/Vincenty%27s_formulae
+use crate::units::{Coordinates, Degrees, Latitude, Longitude, Metres};
+
+/// Mean radius of the Earth, as defined by the IUGG
+pub const EARTH_RADIUS: Metres = Metres(6_371_008.8);
+
+/// WGS 84 semi-major axis in metres
+const A: f64 = 6_378_137.0;
+
+/// WGS 84 flattening
+const F: f64 = 1.0 / 298.257_223_563;
+
+/// WGS 84 semi-minor axis in metres
+const B: f64 = A * (1.0 - F);
+
+impl From<Coordinates> for (Latitude, Longitude) {
+    fn from(value: Coordinates) -> Self {
+        (value.latitude, value.longitude)
+    }
+}
+
+/// Latitude and longitude in radians
+fn radians(position: impl Into<(Latitude, Longitude)>) -> (f64, f64) {
+    let (latitude, longitude) = position.into();
+    (
+        latitude.as_float().to_radians(),
+        longitude.as_float().to_radians(),
+    )
+}
+
+/// Bearing in radians as degrees in the range [0°, 360°)
+fn bearing(radians: f64) -> Degrees {
+    Degrees(radians.to_degrees() as f32).normalised()
+}
+
+/// Great-circle distance between two positions on a spherical Earth
+pub fn haversine_distance(
+    from: impl Into<(Latitude, Longitude)>,
+    to: impl Into<(Latitude, Longitude)>,
+) -> Metres {
+    let (phi1, lambda1) = radians(from);
+    let (phi2, lambda2) = radians(to);
+    let sin_dphi = libm::sin((phi2 - phi1) / 2.0);
+    let sin_dlambda = libm::sin((lambda2 - lambda1) / 2.0);
+    let a = sin_dphi * sin_dphi + libm::cos(phi1) * libm::cos(phi2) * sin_dlambda * sin_dlambda;
+    let c = 2.0 * libm::atan2(libm::sqrt(a), libm::sqrt(1.0 - a));
+    Metres((f64::from(EARTH_RADIUS.0) * c) as f32)
+}
+
+/// Initial bearing of the great circle from one position to another
+///
+/// The bearing of a great circle changes along its length, except along a meridian or the
+/// equator. For coincident positions the bearing is 0°.
+pub fn initial_bearing(
+    from: impl Into<(Latitude, Longitude)>,
+    to: impl Into<(Latitude, Longitude)>,
+) -> Degrees {
+    let (phi1, lambda1) = radians(from);
+    let (phi2, lambda2) = radians(to);
+    let dlambda = lambda2 - lambda1;
+    let y = libm::sin(dlambda) * libm::cos(phi2);
+    let x =
+        libm::cos(phi1) * libm::sin(phi2) - libm::sin(phi1) * libm::cos(phi2) * libm::cos(dlambda);
+    bearing(libm::atan2(y, x))
+}
+
+/// Shortest path between two positions on the WGS 84 ellipsoid
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geodesic {
+    pub distance: Metres,
+    /// Bearing at the starting position
+    pub initial_bearing: Degrees,
+    /// Bearing on arrival at the destination
+    pub final_bearing: Degrees,
+}
+
+/// Geodesic between two positions, using Vincenty's inverse formula
+///
+/// Returns `None` if the formula does not converge, which can happen for nearly antipodal
+/// positions. See T. Vincenty (1975), "Direct and Inverse Solutions of Geodesics on the
+/// Ellipsoid with application of nested equations", Survey Review 23(176).
+pub fn vincenty(
+    from: impl Into<(Latitude, Longitude)>,
+    to: impl Into<(Latitude, Longitude)>,
+) -> Option<Geodesic> {
+    let (phi1, lambda1) = radians(from);
+    let (phi2, lambda2) = radians(to);
+    let l = lambda2 - lambda1;
+    let u1 = libm::atan((1.0 - F) * libm::tan(phi1));
+    let u2 = libm::atan((1.0 - F) * libm::tan(phi2));
+    let (sin_u1, cos_u1) = (libm::sin(u1), libm::cos(u1));
+    let (sin_u2, cos_u2) = (libm::sin(u2), libm::cos(u2));
+
+    let mut lambda = l;
+    for _ in 0..200 {
+        let (sin_lambda, cos_lambda) = (libm::sin(lambda), libm::cos(lambda));
+        let sin_sigma = libm::hypot(
+            cos_u2 * sin_lambda,
+            cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda,
+        );
+        if sin_sigma == 0.0 {
+            return Some(Geodesic {
+                distance: Metres(0.0),
+                initial_bearing: Degrees(0.0),
+                final_bearing: Degrees(0.0),
+            });
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = libm::atan2(sin_sigma, cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos2_alpha = 1.0 - sin_alpha * sin_alpha;
+        // On the equator cos²α is zero, and the term it divides is unused.
+        let cos_2sigma_m = if cos2_alpha == 0.0 {
+            0.0
+        } else {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha
+        };
+        let c = F / 16.0 * cos2_alpha * (4.0 + F * (4.0 - 3.0 * cos2_alpha));
+        let previous = lambda;
+        lambda = l
+            + (1.0 - c)
+                * F
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+        if libm::fabs(lambda - previous) > 1e-12 {
+            continue;
+        }
+
+        let u_squared = cos2_alpha * (A * A - B * B) / (B * B);
+        let big_a = 1.0
+            + u_squared / 16384.0
+                * (4096.0 + u_squared * (-768.0 + u_squared * (320.0 - 175.0 * u_squared)));
+        let big_b = u_squared / 1024.0
+            * (256.0 + u_squared * (-128.0 + u_squared * (74.0 - 47.0 * u_squared)));
+        let delta_sigma = big_b
+            * sin_sigma
+            * (cos_2sigma_m
+                + big_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                        - big_b / 6.0
+                            * cos_2sigma_m
+                            * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                            * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+        let distance = B * big_a * (sigma - delta_sigma);
+
+        let (sin_lambda, cos_lambda) = (libm::sin(lambda), libm::cos(lambda));
+        let alpha1 = libm::atan2(
+            cos_u2 * sin_lambda,
+            cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda,
+        );
+        let alpha2 = libm::atan2(
+            cos_u1 * sin_lambda,
+            -sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda,
+        );
+        return Some(Geodesic {
+            distance: Metres(distance as f32),
+            initial_bearing: bearing(alpha1),
+            final_bearing: bearing(alpha2),
+        });
+    }
+    None
+}
+
+impl Coordinates {
+    /// Distance to `other` on the WGS 84 ellipsoid, ignoring altitude
+    ///
+    /// Uses Vincenty's formula, or the haversine formula if that fails to converge.
+    pub fn distance_to(&self, other: &Coordinates) -> Metres {
+        vincenty(*self, *other).map_or_else(|| haversine_distance(*self, *other), |g| g.distance)
+    }
+
+    /// Initial bearing of the shortest path to `other`
+    ///
+    /// Uses Vincenty's formula, or the great-circle bearing if that fails to converge.
+    pub fn bearing_to(&self, other: &Coordinates) -> Degrees {
+        vincenty(*self, *other)
+            .map_or_else(|| initial_bearing(*self, *other), |g| g.initial_bearing)
+    }
+}
@@ -0,0 +1,233 @@
+//! Home Assistant weather entity forecast JSON
+//!
+//! Hourly and daily forecasts can be converted into the JSON used by [Home Assistant weather
+//! entities], for example to feed a template or RESTful weather entity:
+//!
+//! ```json
+//! {
+//!   "native_temperature_unit": "°C",
+//!   "native_pressure_unit": "hPa",
+//!   "native_wind_speed_unit": "m/s",
+//!   "native_precipitation_unit": "mm",
+//!   "forecast": [
+//!     { "datetime": "2023-07-05T10:00:00Z", "condition": "pouring", "native_temperature": 17.64, … }
+//!   ]
+//! }
+//! ```
+//!
+//! Values are given in this crate's units, apart from pressure, which is converted to
+//! hectopascals. Fields missing from a prediction are left out of its forecast entry.
+//!
+//! Daily forecasts can be given either as one entry per day (Home Assistant's `daily` forecast
+//! type), or as separate day and night entries (`twice_daily`). In both cases the first day, which
+//! is in the past, is skipped.
+//!
+//! [Home Assistant weather entities]: https://developers.home-assistant.io/docs/core/entity/weather/
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use jiff::SignedDuration;
+use serde::Serialize;
+
+use crate::daily::Day;
+use crate::units::{Conditions, PressureUnit};
+use crate::{Daily, Forecast, Hourly};
+
+/// Home Assistant weather condition name for the Met Office conditions
+///
+/// Day and night variants map to the same Home Assistant condition, with the exception of clear
+/// skies, which are `sunny` by day and `clear-night` by night. Thunder without showers maps to
+/// `lightning`, and thunder showers to `lightning-rainy`.
+pub fn condition(conditions: Conditions) -> &'static str {
+    use Conditions::*;
+    match conditions {
+        ClearNight => "clear-night",
+        SunnyDay => "sunny",
+        PartlyCloudyNight | PartlyCloudyDay => "partlycloudy",
+        Mist | Fog => "fog",
+        Cloudy | Overcast => "cloudy",
+        TraceRain | LightRainShowerNight | LightRainShowerDay | Drizzle | LightRain => "rainy",
+        HeavyRainShowerNight | HeavyRainShowerDay | HeavyRain => "pouring",
+        SleetShowerNight | SleetShowerDay | Sleet => "snowy-rainy",
+        HailShowerNight | HailShowerDay | Hail => "hail",
+        LightSnowShowerNight | LightSnowShowerDay | LightSnow | HeavySnowShowerNight
+        | HeavySnowShowerDay | HeavySnow => "snowy",
+        ThunderShowerNight | ThunderShowerDay => "lightning-rainy",
+        Thunder => "lightning",
+    }
+}
+
+#[derive(Serialize)]
+struct Entity {
+    native_temperature_unit: &'static str,
+    native_pressure_unit: &'static str,
+    native_wind_speed_unit: &'static str,
+    native_precipitation_unit: &'static str,
+    forecast: Vec<Entry>,
+}
+
+impl Entity {
+    fn new(forecast: Vec<Entry>) -> Self {
+        Self {
+            native_temperature_unit: "°C",
+            native_pressure_unit: "hPa",
+            native_wind_speed_unit: "m/s",
+            native_precipitation_unit: "mm",
+            forecast,
+        }
+    }
+
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Bug in Home Assistant serialisation")
+    }
+}
+
+/// A single Home Assistant forecast entry
+#[derive(Serialize, Default)]
+struct Entry {
+    datetime: String,
+    condition: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_daytime: Option<bool>,
+    native_temperature: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    native_templow: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    native_apparent_temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    native_dew_point: Option<f32>,
+    humidity: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    precipitation_probability: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    native_precipitation: Option<f32>,
+    native_pressure: f32,
+    native_wind_speed: f32,
+    native_wind_gust_speed: f32,
+    wind_bearing: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uv_index: Option<u8>,
+}
+
+impl Forecast<Hourly> {
+    /// Home Assistant `hourly` forecast JSON
+    pub fn to_home_assistant(&self) -> String {
+        let forecast = self
+            .predictions
+            .iter()
+            .map(|h| Entry {
+                datetime: timestamp(&h.time, SignedDuration::ZERO),
+                condition: condition(h.conditions),
+                native_temperature: h.temperature.0,
+                native_apparent_temperature: Some(h.temperature_feels_like.0),
+                native_dew_point: Some(h.dew_point_temperature.0),
+                humidity: h.relative_humidity.0,
+                precipitation_probability: Some(h.precipitation_probability.0),
+                native_precipitation: h.precipitation_total.map(|p| p.0),
+                native_pressure: PressureUnit::Hectopascals.convert(h.pressure),
+                native_wind_speed: h.wind_speed.0,
+                native_wind_gust_speed: h.wind_gust_speed.0,
+                wind_bearing: h.wind_direction.0,
+                uv_index: Some(h.uv_index.0),
+                ..Entry::default()
+            })
+            .collect();
+        Entity::new(forecast).to_json()
+    }
+}
+
+impl Forecast<Daily> {
+    /// Home Assistant `daily` forecast JSON, with one entry per day
+    ///
+    /// Each entry has the daytime conditions, maximum temperature and midday wind, pressure and
+    /// humidity, with the following night's minimum temperature as `native_templow`. The
+    /// precipitation probability is the greater of the day and night probabilities.
+    pub fn to_home_assistant_daily(&self) -> String {
+        let forecast = self
+            .predictions
+            .iter()
+            .filter_map(|daily| {
+                let mut entry = day_entry(daily)?;
+                entry.is_daytime = None;
+                entry.native_templow = Some(daily.night.temperature_minimum.most_likely.0);
+                entry.precipitation_probability = entry
+                    .precipitation_probability
+                    .map(|p| p.max(daily.night.precipitation_probability.0));
+                entry.datetime = timestamp(&daily.time, SignedDuration::ZERO);
+                Some(entry)
+            })
+            .collect();
+        Entity::new(forecast).to_json()
+    }
+
+    /// Home Assistant `twice_daily` forecast JSON, with separate day and night entries
+    ///
+    /// Day entries are timestamped at midday and night entries at the following midnight, the
+    /// times at which the Met Office gives the wind, pressure and humidity values.
+    pub fn to_home_assistant_twice_daily(&self) -> String {
+        let forecast = self
+            .predictions
+            .iter()
+            .filter_map(|daily| Some([day_entry(daily)?, night_entry(daily)]))
+            .flatten()
+            .collect();
+        Entity::new(forecast).to_json()
+    }
+}
+
+/// Daytime entry for a day in the future, or `None` for the past day
+fn day_entry(daily: &Daily) -> Option<Entry> {
+    let Day::Future {
+        conditions,
+        temperature_maximum,
+        temperature_feels_like_maximum,
+        relative_humidity,
+        precipitation_probability,
+        pressure,
+        uv_index_maximum,
+        wind_speed,
+        wind_direction,
+        wind_gust_speed,
+        ..
+    } = &daily.day
+    else {
+        return None;
+    };
+    Some(Entry {
+        datetime: timestamp(&daily.time, SignedDuration::from_hours(12)),
+        condition: condition(*conditions),
+        is_daytime: Some(true),
+        native_temperature: temperature_maximum.most_likely.0,
+        native_apparent_temperature: Some(temperature_feels_like_maximum.most_likely.0),
+        humidity: relative_humidity.0,
+        precipitation_probability: Some(precipitation_probability.0),
+        native_pressure: PressureUnit::Hectopascals.convert(*pressure),
+        native_wind_speed: wind_speed.0,
+        native_wind_gust_speed: wind_gust_speed.0,
+        wind_bearing: wind_direction.0,
+        uv_index: Some(uv_index_maximum.0),
+        ..Entry::default()
+    })
+}
+
+fn night_entry(daily: &Daily) -> Entry {
+    let night = &daily.night;
+    Entry {
+        datetime: timestamp(&daily.time, SignedDuration::from_hours(24)),
+        condition: condition(night.conditions),
+        is_daytime: Some(false),
+        native_temperature: night.temperature_minimum.most_likely.0,
+        native_apparent_temperature: Some(night.temperature_feels_like_minimum.most_likely.0),
+        humidity: night.relative_humidity.0,
+        precipitation_probability: Some(night.precipitation_probability.0),
+        native_pressure: PressureUnit::Hectopascals.convert(night.pressure),
+        native_wind_speed: night.wind_speed.0,
+        native_wind_gust_speed: night.wind_gust_speed.0,
+        wind_bearing: night.wind_direction.0,
+        ..Entry::default()
+    }
+}
+
+fn timestamp(time: &jiff::Zoned, offset: SignedDuration) -> String {
+    (time.timestamp() + offset).to_string()
+}
@@ -1,8 +1,8 @@
 use crate::Error;
 use crate::parse::RawHourlyForecast;
 use crate::units::{
-    Celsius, Conditions, Degrees, Metres, MetresPerSecond, Millimetres, MillimetresPerHour,
-    Pascals, Percentage, UvIndex,
+    Celsius, Conditions, Degrees, MetresPerSecond, Millimetres, MillimetresPerHour, Pascals,
+    Percentage, UvIndex, Visibility,
 };
 
 /// Forecast for a particular hour
@@ -96,7 +96,7 @@ pub struct Hourly {
     /// Appears to be missing after 48 hours.
     pub wind_gust_hourly_maximum_speed: Option<MetresPerSecond>,
     /// Distance in metres at which a known object can be seen horizontally from screen level (1.5m.)
-    pub visibility: Metres,
+    pub visibility: Visibility,
     /// Percent relative humidity at screen level (1.5m).
     pub relative_humidity: Percentage,
     /// Air pressure at mean sea level in Pascals.
@@ -126,7 +126,7 @@ impl TryFrom<RawHourlyForecast> for Hourly {
             wind_direction: Degrees(rf.wind_direction_from_10m),
             wind_gust_speed: MetresPerSecond(rf.wind_gust_speed_10m),
             wind_gust_hourly_maximum_speed: rf.max_10m_wind_gust.map(MetresPerSecond),
-            visibility: Metres(rf.visibility),
+            visibility: Visibility(rf.visibility),
             relative_humidity: Percentage(rf.screen_relative_humidity),
             pressure: Pascals(rf.mslp),
             uv_index: UvIndex(rf.uv_index),
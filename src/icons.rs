@@ -0,0 +1,57 @@
+//! Bundled SVG weather icons, enabled by the `icons` feature
+//!
+//! There is one icon for each [`Conditions`] variant, with separate day and night icons where the
+//! Met Office distinguishes them. Each icon is a self-contained 64×64 SVG document with a
+//! `<title>` element for accessibility, suitable for embedding directly in HTML or rasterising
+//! for e-ink displays.
+//!
+//! The icons were drawn for this crate and are licensed under the same terms as the crate itself
+//! (MIT or Apache-2.0).
+use crate::units::Conditions;
+
+impl Conditions {
+    /// SVG icon for these conditions
+    ///
+    /// ```
+    /// use rjw_metoffice::units::Conditions;
+    ///
+    /// let svg = Conditions::PartlyCloudyDay.svg_icon();
+    /// assert!(svg.starts_with("<svg "));
+    /// ```
+    pub fn svg_icon(&self) -> &'static str {
+        use Conditions::*;
+        match self {
+            TraceRain => include_str!("../icons/trace-rain.svg"),
+            ClearNight => include_str!("../icons/clear-night.svg"),
+            SunnyDay => include_str!("../icons/sunny-day.svg"),
+            PartlyCloudyNight => include_str!("../icons/partly-cloudy-night.svg"),
+            PartlyCloudyDay => include_str!("../icons/partly-cloudy-day.svg"),
+            Mist => include_str!("../icons/mist.svg"),
+            Fog => include_str!("../icons/fog.svg"),
+            Cloudy => include_str!("../icons/cloudy.svg"),
+            Overcast => include_str!("../icons/overcast.svg"),
+            LightRainShowerNight => include_str!("../icons/light-rain-shower-night.svg"),
+            LightRainShowerDay => include_str!("../icons/light-rain-shower-day.svg"),
+            Drizzle => include_str!("../icons/drizzle.svg"),
+            LightRain => include_str!("../icons/light-rain.svg"),
+            HeavyRainShowerNight => include_str!("../icons/heavy-rain-shower-night.svg"),
+            HeavyRainShowerDay => include_str!("../icons/heavy-rain-shower-day.svg"),
+            HeavyRain => include_str!("../icons/heavy-rain.svg"),
+            SleetShowerNight => include_str!("../icons/sleet-shower-night.svg"),
+            SleetShowerDay => include_str!("../icons/sleet-shower-day.svg"),
+            Sleet => include_str!("../icons/sleet.svg"),
+            HailShowerNight => include_str!("../icons/hail-shower-night.svg"),
+            HailShowerDay => include_str!("../icons/hail-shower-day.svg"),
+            Hail => include_str!("../icons/hail.svg"),
+            LightSnowShowerNight => include_str!("../icons/light-snow-shower-night.svg"),
+            LightSnowShowerDay => include_str!("../icons/light-snow-shower-day.svg"),
+            LightSnow => include_str!("../icons/light-snow.svg"),
+            HeavySnowShowerNight => include_str!("../icons/heavy-snow-shower-night.svg"),
+            HeavySnowShowerDay => include_str!("../icons/heavy-snow-shower-day.svg"),
+            HeavySnow => include_str!("../icons/heavy-snow.svg"),
+            ThunderShowerNight => include_str!("../icons/thunder-shower-night.svg"),
+            ThunderShowerDay => include_str!("../icons/thunder-shower-day.svg"),
+            Thunder => include_str!("../icons/thunder.svg"),
+        }
+    }
+}
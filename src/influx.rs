@@ -0,0 +1,101 @@
+//! InfluxDB line protocol serialisation of forecasts
+//!
+//! Each prediction becomes one point in the given measurement. Points are tagged with the
+//! forecast location name, the site latitude and longitude, and the time the model was run
+//! (`predictions_made_at`), and are timestamped in nanoseconds with the prediction time. The
+//! location name tag is left out if the name is empty, as InfluxDB rejects empty tag values.
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let lines = forecast.to_line_protocol("weather");
+//! assert!(lines.starts_with("weather,location_name=Exeter\\ Met\\ Office,"));
+//! ```
+//!
+//! Field keys are the field names used by this crate, for example `wind_gust_speed`. Fields that
+//! are missing from a prediction, such as the hourly `temperature_maximum` in the final hours of a
+//! forecast, are left out of that point. The significant weather code, UV index and pressure in
+//! Pascals are written as integers, and all other fields as floats.
+//!
+//! Daily predictions are flattened, so that the [`Day`] and [`Night`] fields are prefixed with
+//! `day_` and `night_`, and each [`TemperaturePrediction`] is split into three fields, for example
+//! `night_temperature_minimum`, `night_temperature_minimum_upper_bound` and
+//! `night_temperature_minimum_lower_bound`.
+//!
+//! [`Day`]: crate::daily::Day
+//! [`Night`]: crate::daily::Night
+//! [`TemperaturePrediction`]: crate::daily::TemperaturePrediction
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::fields::{Fields, Unit};
+use crate::{Daily, Forecast, Hourly, ThreeHourly, TimePeriod};
+
+impl Forecast<Hourly> {
+    /// Serialise all predictions as InfluxDB line protocol points, one per line
+    pub fn to_line_protocol(&self, measurement: &str) -> String {
+        render(self, measurement)
+    }
+}
+
+impl Forecast<ThreeHourly> {
+    /// Serialise all predictions as InfluxDB line protocol points, one per line
+    pub fn to_line_protocol(&self, measurement: &str) -> String {
+        render(self, measurement)
+    }
+}
+
+impl Forecast<Daily> {
+    /// Serialise all predictions as InfluxDB line protocol points, one per line
+    ///
+    /// Day and night fields are prefixed with `day_` and `night_`.
+    pub fn to_line_protocol(&self, measurement: &str) -> String {
+        render(self, measurement)
+    }
+}
+
+fn render<T: TimePeriod + Fields>(forecast: &Forecast<T>, measurement: &str) -> String {
+    let mut series = String::new();
+    escape_into(&mut series, measurement, &[',', ' ']);
+    if !forecast.location_name.is_empty() {
+        series.push_str(",location_name=");
+        escape_into(&mut series, &forecast.location_name, &[',', '=', ' ']);
+    }
+    // Writing to a String cannot fail.
+    let _ = write!(
+        series,
+        ",latitude={},longitude={},predictions_made_at={}",
+        forecast.coordinates.latitude.as_float(),
+        forecast.coordinates.longitude.as_float(),
+        forecast.predictions_made_at.timestamp(),
+    );
+
+    let mut out = String::new();
+    for prediction in &forecast.predictions {
+        out.push_str(&series);
+        for (i, field) in prediction.fields().into_iter().enumerate() {
+            out.push(if i == 0 { ' ' } else { ',' });
+            out.push_str(field.name);
+            let value = field.value.as_f32();
+            let _ = match field.value.unit() {
+                Unit::Code | Unit::Index | Unit::Pascals => write!(out, "={}i", value as i64),
+                _ => write!(out, "={value}"),
+            };
+        }
+        let _ = writeln!(out, " {}", prediction.time().timestamp().as_nanosecond());
+    }
+    out
+}
+
+/// Push `s` on to `out`, escaping the given special characters with a backslash
+///
+/// Line protocol has no way to escape line breaks, so they are replaced with spaces.
+fn escape_into(out: &mut String, s: &str, special: &[char]) {
+    for c in s.chars() {
+        let c = if matches!(c, '\n' | '\r') { ' ' } else { c };
+        if special.contains(&c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
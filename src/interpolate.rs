@@ -0,0 +1,212 @@
+//! Looking up and interpolating predictions at arbitrary times
+use jiff::Timestamp;
+
+use crate::compass::WindVector;
+use crate::units::{
+    Celsius, Degrees, MetresPerSecond, Millimetres, MillimetresPerHour, Pascals, Percentage,
+    Visibility,
+};
+use crate::{Error, Forecast, Hourly, ThreeHourly, TimePeriod};
+
+impl<T: TimePeriod> Forecast<T> {
+    /// Prediction covering the given time
+    ///
+    /// Each prediction is taken to cover the period from its `time` until the next prediction
+    /// (see [`TimePeriod::PERIOD`]), so 15:20 falls in the 15:00 hourly prediction.
+    ///
+    /// Returns [`Error::TimeOutsideForecast`] if the time is before the first prediction or after
+    /// the period of the last.
+    ///
+    /// ```
+    /// # use rjw_metoffice::{Forecast, Hourly};
+    /// # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+    /// let time: jiff::Timestamp = "2023-07-05T15:20Z".parse().unwrap();
+    /// let hour = forecast.at(time).unwrap();
+    /// assert_eq!(hour.time.strftime("%H:%M").to_string(), "15:00");
+    /// ```
+    pub fn at(&self, time: impl Into<Timestamp>) -> Result<&T, Error> {
+        let time = time.into();
+        let i = self
+            .predictions
+            .partition_point(|p| p.time().timestamp() <= time);
+        i.checked_sub(1)
+            .map(|i| &self.predictions[i])
+            .filter(|p| time < p.time().timestamp() + T::PERIOD)
+            .ok_or(Error::TimeOutsideForecast(time))
+    }
+
+    /// Predictions either side of `time`, which must be within the forecast
+    fn between(&self, time: Timestamp) -> Result<Between<'_, T>, Error> {
+        let outside = || Error::TimeOutsideForecast(time);
+        let i = self
+            .predictions
+            .partition_point(|p| p.time().timestamp() < time);
+        let after = self.predictions.get(i).ok_or_else(outside)?;
+        let after_time = after.time().timestamp();
+        if after_time == time {
+            return Ok(Between {
+                before: after,
+                after,
+                fraction: 0.0,
+            });
+        }
+        let before = i
+            .checked_sub(1)
+            .map(|i| &self.predictions[i])
+            .ok_or_else(outside)?;
+        let before_time = before.time().timestamp();
+        let fraction = time.duration_since(before_time).as_secs_f64()
+            / after_time.duration_since(before_time).as_secs_f64();
+        Ok(Between {
+            before,
+            after,
+            fraction: fraction as f32,
+        })
+    }
+}
+
+/// Two consecutive predictions, and how far a time is from the first to the second
+struct Between<'a, T> {
+    before: &'a T,
+    after: &'a T,
+    fraction: f32,
+}
+
+impl<'a, T> Between<'a, T> {
+    /// Linear interpolation of a field
+    fn mix(&self, field: impl Fn(&T) -> f32) -> f32 {
+        let (a, b) = (field(self.before), field(self.after));
+        a + (b - a) * self.fraction
+    }
+
+    /// Linear interpolation of an optional field, if it is present in both predictions
+    fn mix_option(&self, field: impl Fn(&T) -> Option<f32>) -> Option<f32> {
+        let (a, b) = (field(self.before)?, field(self.after)?);
+        Some(a + (b - a) * self.fraction)
+    }
+
+    /// The closer of the two predictions
+    fn nearest(&self) -> &'a T {
+        if self.fraction < 0.5 {
+            self.before
+        } else {
+            self.after
+        }
+    }
+
+    /// Wind speed and direction, interpolated as u/v vectors
+    fn wind(&self, field: impl Fn(&T) -> (MetresPerSecond, Degrees)) -> (MetresPerSecond, Degrees) {
+        let vector = |p| {
+            let (speed, direction) = field(p);
+            WindVector::new(speed, direction)
+        };
+        let (a, b) = (vector(self.before), vector(self.after));
+        let wind = WindVector {
+            u: MetresPerSecond(a.u.0 + (b.u.0 - a.u.0) * self.fraction),
+            v: MetresPerSecond(a.v.0 + (b.v.0 - a.v.0) * self.fraction),
+        };
+        (wind.speed(), wind.direction())
+    }
+
+    fn pressure(&self, field: impl Fn(&T) -> Pascals) -> Pascals {
+        Pascals(libm::roundf(self.mix(|p| field(p).0 as f32)) as u32)
+    }
+}
+
+impl Forecast<Hourly> {
+    /// Prediction at any time within the forecast, interpolated between the hours either side
+    ///
+    /// Temperatures, humidity, pressure, visibility, probabilities and precipitation are
+    /// interpolated linearly. Wind is interpolated as u/v vectors, so the wind speed is lower
+    /// midway between hours with different wind directions. Optional fields are present only if
+    /// they are present in both hours. The conditions and UV index are taken from the nearer
+    /// hour, as they are categories.
+    ///
+    /// Returns [`Error::TimeOutsideForecast`] if the time is before the first prediction or after
+    /// the last.
+    ///
+    /// ```
+    /// # use rjw_metoffice::{Forecast, Hourly};
+    /// # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+    /// let time: jiff::Timestamp = "2023-07-05T15:20Z".parse().unwrap();
+    /// let prediction = forecast.interpolate(time).unwrap();
+    /// assert_eq!(format!("{:.1}", prediction.temperature), "19.0°C");
+    /// ```
+    pub fn interpolate(&self, time: impl Into<Timestamp>) -> Result<Hourly, Error> {
+        let time = time.into();
+        let x = self.between(time)?;
+        let nearest = x.nearest();
+        let (wind_speed, wind_direction) = x.wind(|h| (h.wind_speed, h.wind_direction));
+        Ok(Hourly {
+            time: time.to_zoned(nearest.time.time_zone().clone()),
+            conditions: nearest.conditions,
+            temperature: Celsius(x.mix(|h| h.temperature.0)),
+            temperature_maximum: x
+                .mix_option(|h| h.temperature_maximum.map(|t| t.0))
+                .map(Celsius),
+            temperature_minimum: x
+                .mix_option(|h| h.temperature_minimum.map(|t| t.0))
+                .map(Celsius),
+            temperature_feels_like: Celsius(x.mix(|h| h.temperature_feels_like.0)),
+            dew_point_temperature: Celsius(x.mix(|h| h.dew_point_temperature.0)),
+            precipitation_probability: Percentage(x.mix(|h| h.precipitation_probability.0)),
+            precipitation_rate: MillimetresPerHour(x.mix(|h| h.precipitation_rate.0)),
+            precipitation_total: x
+                .mix_option(|h| h.precipitation_total.map(|p| p.0))
+                .map(Millimetres),
+            snow_total: x.mix_option(|h| h.snow_total.map(|p| p.0)).map(Millimetres),
+            wind_speed,
+            wind_direction,
+            wind_gust_speed: MetresPerSecond(x.mix(|h| h.wind_gust_speed.0)),
+            wind_gust_hourly_maximum_speed: x
+                .mix_option(|h| h.wind_gust_hourly_maximum_speed.map(|s| s.0))
+                .map(MetresPerSecond),
+            visibility: Visibility(x.mix(|h| h.visibility.0)),
+            relative_humidity: Percentage(x.mix(|h| h.relative_humidity.0)),
+            pressure: x.pressure(|h| h.pressure),
+            uv_index: nearest.uv_index,
+        })
+    }
+}
+
+impl Forecast<ThreeHourly> {
+    /// Prediction at any time within the forecast, interpolated between the periods either side
+    ///
+    /// Fields are interpolated as for [`Forecast::<Hourly>::interpolate`], with the conditions
+    /// and UV index taken from the nearer prediction.
+    ///
+    /// Returns [`Error::TimeOutsideForecast`] if the time is before the first prediction or after
+    /// the last.
+    pub fn interpolate(&self, time: impl Into<Timestamp>) -> Result<ThreeHourly, Error> {
+        let time = time.into();
+        let x = self.between(time)?;
+        let nearest = x.nearest();
+        let (wind_speed, wind_direction) = x.wind(|t| (t.wind_speed, t.wind_direction));
+        Ok(ThreeHourly {
+            time: time.to_zoned(nearest.time.time_zone().clone()),
+            conditions: nearest.conditions,
+            temperature_maximum: Celsius(x.mix(|t| t.temperature_maximum.0)),
+            temperature_minimum: Celsius(x.mix(|t| t.temperature_minimum.0)),
+            temperature_feels_like: Celsius(x.mix(|t| t.temperature_feels_like.0)),
+            wind_speed,
+            wind_direction,
+            wind_gust_speed: MetresPerSecond(x.mix(|t| t.wind_gust_speed.0)),
+            wind_gust_three_hourly_maximum: MetresPerSecond(
+                x.mix(|t| t.wind_gust_three_hourly_maximum.0),
+            ),
+            visibility: Visibility(x.mix(|t| t.visibility.0)),
+            relative_humidity: Percentage(x.mix(|t| t.relative_humidity.0)),
+            pressure: x.pressure(|t| t.pressure),
+            uv_index: nearest.uv_index,
+            precipitation_total: Millimetres(x.mix(|t| t.precipitation_total.0)),
+            snow_total: Millimetres(x.mix(|t| t.snow_total.0)),
+            precipitation_probability: Percentage(x.mix(|t| t.precipitation_probability.0)),
+            rain_probability: Percentage(x.mix(|t| t.rain_probability.0)),
+            heavy_rain_probability: Percentage(x.mix(|t| t.heavy_rain_probability.0)),
+            snow_probability: Percentage(x.mix(|t| t.snow_probability.0)),
+            heavy_snow_probability: Percentage(x.mix(|t| t.heavy_snow_probability.0)),
+            hail_probability: Percentage(x.mix(|t| t.hail_probability.0)),
+            lightning_probability: Percentage(x.mix(|t| t.lightning_probability.0)),
+        })
+    }
+}
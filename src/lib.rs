@@ -70,6 +70,11 @@
 //! Similarly, the generic is used to determine parsing behaviour via string or byte slices
 //! (`FromStr` or `TryFrom<&[u8]>`).
 //!
+//! Forecasts for all three time periods at one site can be combined with a [`Timeline`], which
+//! uses the finest resolution available at each time.
+//!
+//! [`Timeline`]: crate::timeline::Timeline
+//!
 //! ## Prediction field names
 //!
 //! Generally, the Met Office field names are not used. This is to make field names consistent
@@ -82,6 +87,20 @@
 //!
 //! If you feel particularly strongly that anything is misnamed, please open an issue.
 //!
+//! ## Display units
+//!
+//! Prediction fields use the Met Office's own units (°C, m/s, Pa, …). The [`units`] module
+//! converts these to other units, such as `Fahrenheit::from(celsius)`. A [`UnitSystem`] preset
+//! (UK, US, metric or marine) formats single values, such as with `format_temperature`, or a
+//! whole prediction with `formatted`.
+//!
+//! Display strings are in English. The [`locale`] module has Welsh, French, German and Spanish
+//! equivalents for weather conditions, UV advice, compass directions and unit symbols.
+//!
+//! [`units`]: crate::units
+//! [`UnitSystem`]: crate::units::UnitSystem
+//! [`locale`]: crate::locale
+//!
 //! ## Missing data
 //!
 //! **Hourly** forecasts contain 49 separate sets of predictions (start + 48 hours), but I have
@@ -110,6 +129,26 @@
 //! [past]: crate::daily::Day::Past
 //! [future]: crate::daily::Day::Future
 //!
+//! ## Exporting forecasts
+//!
+//! Forecasts can be rendered into formats understood by other tools:
+//!
+//! - [OpenMetrics] text for Prometheus, via `Forecast::to_openmetrics`.
+//! - [InfluxDB line protocol], via `Forecast::to_line_protocol`.
+//! - [Home Assistant] weather entity forecasts, via `Forecast::to_home_assistant` and friends.
+//! - [Open-Meteo]-shaped JSON responses, via `Forecast::to_open_meteo`.
+//!
+//! [OpenMetrics]: crate::openmetrics
+//! [InfluxDB line protocol]: crate::influx
+//! [Home Assistant]: crate::home_assistant
+//! [Open-Meteo]: crate::open_meteo
+//!
+//! ## Cargo features
+//!
+//! - `std`: enables the small HTTP endpoint in [`openmetrics::serve`].
+//! - `icons`: bundles an SVG weather icon for each set of conditions, available through
+//!   `Conditions::svg_icon`.
+//!
 //! ## Memory usage
 //!
 //! While this crate is `no_std`, it still requires a memory allocator (ie, uses `alloc`).
@@ -121,15 +160,40 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+pub mod alerts;
+pub mod beaufort;
+pub mod calendar;
+pub mod compass;
 pub mod daily;
 mod error;
+mod fields;
 mod forecast;
+pub mod format;
+pub mod geo;
+pub mod geodesic;
+pub mod home_assistant;
 mod hourly;
+#[cfg(feature = "icons")]
+mod icons;
+pub mod influx;
+mod interpolate;
+pub mod locale;
+pub mod meteogram;
+pub mod open_meteo;
+pub mod openmetrics;
 mod parse;
+pub mod precipitation;
+pub mod resample;
 mod sealed;
+pub mod summary;
+pub mod thermodynamics;
 mod three_hourly;
+pub mod timeline;
 pub mod units;
+pub mod uv;
 
 pub use daily::Daily;
 pub use error::Error;
@@ -0,0 +1,409 @@
+//! Localised display strings
+//!
+//! The `Display` impls in this crate are in English. A [`Locale`] gives the equivalent text in
+//! Welsh, French, German or Spanish for weather conditions, UV index bands and advice, compass
+//! directions and unit symbols:
+//!
+//! ```
+//! use rjw_metoffice::locale::Locale;
+//! use rjw_metoffice::units::Conditions;
+//!
+//! let locale = Locale::from_tag("cy-GB").unwrap();
+//! assert_eq!(locale.conditions(Conditions::HeavyRain), "Glaw trwm");
+//! assert_eq!(Locale::German.conditions(Conditions::HeavyRain), "Starker Regen");
+//! ```
+//!
+//! Translations are held in static tables, so no allocation is needed. Any string missing from a
+//! catalogue falls back to English. In particular, only the 16-point compass directions are
+//! translated, so the "by" points of the 32-point compass (such as "NbE") are always in English.
+use crate::compass::CompassPoints;
+use crate::units::{
+    Conditions, Degrees, DistanceUnit, PrecipitationUnit, PressureUnit, SpeedUnit, TemperatureUnit,
+    UvCategory,
+};
+
+/// Language used for display strings
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    English,
+    Welsh,
+    French,
+    German,
+    Spanish,
+}
+
+impl Locale {
+    /// Locale for a BCP 47 language tag, such as "fr" or "de-AT"
+    ///
+    /// Only the primary language subtag is used, case-insensitively. Returns `None` for languages
+    /// without a catalogue.
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        [
+            Locale::English,
+            Locale::Welsh,
+            Locale::French,
+            Locale::German,
+            Locale::Spanish,
+        ]
+        .into_iter()
+        .find(|locale| locale.tag().eq_ignore_ascii_case(language))
+    }
+
+    /// ISO 639-1 language code, such as "cy"
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Welsh => "cy",
+            Locale::French => "fr",
+            Locale::German => "de",
+            Locale::Spanish => "es",
+        }
+    }
+
+    /// Description of the weather conditions, such as "Heavy rain"
+    pub fn conditions(&self, conditions: Conditions) -> &'static str {
+        self.translate(conditions.description())
+    }
+
+    /// Name of the UV index band, such as "Very high"
+    pub fn uv_category(&self, category: UvCategory) -> &'static str {
+        self.translate(category.label())
+    }
+
+    /// Sun protection advice for the UV index band
+    pub fn uv_advice(&self, category: UvCategory) -> &'static str {
+        self.translate(category.advice())
+    }
+
+    /// Abbreviated compass direction, such as "WSW" (or "OSO" in French)
+    pub fn compass(&self, direction: Degrees, points: CompassPoints) -> &'static str {
+        self.translate(direction.compass(points))
+    }
+
+    /// Temperature unit symbol
+    pub fn temperature_unit(&self, unit: TemperatureUnit) -> &'static str {
+        self.translate(unit.symbol())
+    }
+
+    /// Speed unit symbol, such as "mph" (or "mya" in Welsh)
+    pub fn speed_unit(&self, unit: SpeedUnit) -> &'static str {
+        self.translate(unit.symbol())
+    }
+
+    /// Pressure unit symbol
+    pub fn pressure_unit(&self, unit: PressureUnit) -> &'static str {
+        self.translate(unit.symbol())
+    }
+
+    /// Distance unit symbol
+    pub fn distance_unit(&self, unit: DistanceUnit) -> &'static str {
+        self.translate(unit.symbol())
+    }
+
+    /// Precipitation unit symbol
+    pub fn precipitation_unit(&self, unit: PrecipitationUnit) -> &'static str {
+        self.translate(unit.symbol())
+    }
+
+    /// Translation of an English string, or the English string if it is missing
+    fn translate(&self, english: &'static str) -> &'static str {
+        let catalogue: &[(&str, &str)] = match self {
+            Locale::English => &[],
+            Locale::Welsh => WELSH,
+            Locale::French => FRENCH,
+            Locale::German => GERMAN,
+            Locale::Spanish => SPANISH,
+        };
+        catalogue
+            .iter()
+            .find(|(en, _)| *en == english)
+            .map_or(english, |(_, translated)| translated)
+    }
+}
+
+impl core::fmt::Display for Locale {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.tag())
+    }
+}
+
+/// (English, Welsh) pairs
+const WELSH: &[(&str, &str)] = &[
+    // Conditions
+    ("Trace of rain", "Ychydig o law"),
+    ("Clear", "Clir"),
+    ("Sunny", "Heulog"),
+    ("Partly Cloudy", "Rhannol gymylog"),
+    ("Mist", "Tarth"),
+    ("Fog", "Niwl"),
+    ("Cloudy", "Cymylog"),
+    ("Overcast", "Cymylau trwchus"),
+    ("Light rain shower", "Cawod ysgafn o law"),
+    ("Drizzle", "Glaw mân"),
+    ("Light rain", "Glaw ysgafn"),
+    ("Heavy rain shower", "Cawod drom o law"),
+    ("Heavy rain", "Glaw trwm"),
+    ("Sleet shower", "Cawod o eirlaw"),
+    ("Sleet", "Eirlaw"),
+    ("Hail shower", "Cawod o genllysg"),
+    ("Hail", "Cenllysg"),
+    ("Light snow shower", "Cawod ysgafn o eira"),
+    ("Light snow", "Eira ysgafn"),
+    ("Heavy snow shower", "Cawod drom o eira"),
+    ("Heavy snow", "Eira trwm"),
+    ("Thunder shower", "Cawod daranau"),
+    ("Thunder", "Taranau"),
+    // UV index
+    ("Low", "Isel"),
+    ("Moderate", "Cymedrol"),
+    ("High", "Uchel"),
+    ("Very high", "Uchel iawn"),
+    ("Extreme", "Eithafol"),
+    (
+        "No protection required. You can safely stay outside.",
+        "Does dim angen amddiffyniad. Gallwch aros y tu allan yn ddiogel.",
+    ),
+    (
+        "Take care during midday hours and do not spend too much time in the sun unprotected.",
+        "Byddwch yn ofalus yng nghanol y dydd a pheidiwch â threulio gormod o amser yn yr haul \
+         heb amddiffyniad.",
+    ),
+    (
+        "Seek shade during midday hours, cover up and wear sunscreen.",
+        "Chwiliwch am gysgod yng nghanol y dydd, gorchuddiwch eich croen a gwisgwch eli haul.",
+    ),
+    (
+        "Spend time in the shade between 11am and 3pm. Shirt, sunscreen and hat are essential.",
+        "Arhoswch yn y cysgod rhwng 11am a 3pm. Mae crys, eli haul a het yn hanfodol.",
+    ),
+    (
+        "Avoid being outside during midday hours. Shirt, sunscreen and hat are essential.",
+        "Osgowch fod y tu allan yng nghanol y dydd. Mae crys, eli haul a het yn hanfodol.",
+    ),
+    // Compass: Gogledd, Dwyrain (Ddwyrain after another point), De, Gorllewin
+    ("N", "G"),
+    ("NNE", "GGDd"),
+    ("NE", "GDd"),
+    ("ENE", "DwGDd"),
+    ("E", "Dw"),
+    ("ESE", "DwDDd"),
+    ("SE", "DDd"),
+    ("SSE", "DDDd"),
+    ("S", "D"),
+    ("SSW", "DDGn"),
+    ("SW", "DGn"),
+    ("WSW", "GnDGn"),
+    ("W", "Gn"),
+    ("WNW", "GnGGn"),
+    ("NW", "GGn"),
+    ("NNW", "GGGn"),
+    // Units
+    ("mph", "mya"),
+];
+
+/// (English, French) pairs
+const FRENCH: &[(&str, &str)] = &[
+    // Conditions
+    ("Trace of rain", "Traces de pluie"),
+    ("Clear", "Ciel dégagé"),
+    ("Sunny", "Ensoleillé"),
+    ("Partly Cloudy", "Partiellement nuageux"),
+    ("Mist", "Brume"),
+    ("Fog", "Brouillard"),
+    ("Cloudy", "Nuageux"),
+    ("Overcast", "Couvert"),
+    ("Light rain shower", "Faible averse de pluie"),
+    ("Drizzle", "Bruine"),
+    ("Light rain", "Pluie faible"),
+    ("Heavy rain shower", "Forte averse de pluie"),
+    ("Heavy rain", "Forte pluie"),
+    ("Sleet shower", "Averse de neige fondue"),
+    ("Sleet", "Neige fondue"),
+    ("Hail shower", "Averse de grêle"),
+    ("Hail", "Grêle"),
+    ("Light snow shower", "Faible averse de neige"),
+    ("Light snow", "Neige faible"),
+    ("Heavy snow shower", "Forte averse de neige"),
+    ("Heavy snow", "Forte neige"),
+    ("Thunder shower", "Averse orageuse"),
+    ("Thunder", "Orage"),
+    // UV index
+    ("Low", "Faible"),
+    ("Moderate", "Modéré"),
+    ("High", "Élevé"),
+    ("Very high", "Très élevé"),
+    ("Extreme", "Extrême"),
+    (
+        "No protection required. You can safely stay outside.",
+        "Aucune protection nécessaire. Vous pouvez rester dehors en toute sécurité.",
+    ),
+    (
+        "Take care during midday hours and do not spend too much time in the sun unprotected.",
+        "Soyez prudent aux heures de midi et ne restez pas trop longtemps au soleil sans \
+         protection.",
+    ),
+    (
+        "Seek shade during midday hours, cover up and wear sunscreen.",
+        "Recherchez l'ombre aux heures de midi, couvrez-vous et mettez de la crème solaire.",
+    ),
+    (
+        "Spend time in the shade between 11am and 3pm. Shirt, sunscreen and hat are essential.",
+        "Restez à l'ombre entre 11 h et 15 h. T-shirt, crème solaire et chapeau sont \
+         indispensables.",
+    ),
+    (
+        "Avoid being outside during midday hours. Shirt, sunscreen and hat are essential.",
+        "Évitez de sortir aux heures de midi. T-shirt, crème solaire et chapeau sont \
+         indispensables.",
+    ),
+    // Compass
+    ("SSW", "SSO"),
+    ("SW", "SO"),
+    ("WSW", "OSO"),
+    ("W", "O"),
+    ("WNW", "ONO"),
+    ("NW", "NO"),
+    ("NNW", "NNO"),
+    // Units
+    ("mph", "mi/h"),
+    ("kn", "nd"),
+    ("inHg", "po Hg"),
+    ("in", "po"),
+    ("nmi", "M"),
+];
+
+/// (English, German) pairs
+const GERMAN: &[(&str, &str)] = &[
+    // Conditions
+    ("Trace of rain", "Spuren von Regen"),
+    ("Clear", "Klar"),
+    ("Sunny", "Sonnig"),
+    ("Partly Cloudy", "Teilweise bewölkt"),
+    ("Mist", "Dunst"),
+    ("Fog", "Nebel"),
+    ("Cloudy", "Bewölkt"),
+    ("Overcast", "Bedeckt"),
+    ("Light rain shower", "Leichter Regenschauer"),
+    ("Drizzle", "Nieselregen"),
+    ("Light rain", "Leichter Regen"),
+    ("Heavy rain shower", "Starker Regenschauer"),
+    ("Heavy rain", "Starker Regen"),
+    ("Sleet shower", "Schneeregenschauer"),
+    ("Sleet", "Schneeregen"),
+    ("Hail shower", "Hagelschauer"),
+    ("Hail", "Hagel"),
+    ("Light snow shower", "Leichter Schneeschauer"),
+    ("Light snow", "Leichter Schneefall"),
+    ("Heavy snow shower", "Starker Schneeschauer"),
+    ("Heavy snow", "Starker Schneefall"),
+    ("Thunder shower", "Gewitterschauer"),
+    ("Thunder", "Gewitter"),
+    // UV index
+    ("Low", "Niedrig"),
+    ("Moderate", "Mäßig"),
+    ("High", "Hoch"),
+    ("Very high", "Sehr hoch"),
+    ("Extreme", "Extrem"),
+    (
+        "No protection required. You can safely stay outside.",
+        "Kein Schutz erforderlich. Sie können sich gefahrlos im Freien aufhalten.",
+    ),
+    (
+        "Take care during midday hours and do not spend too much time in the sun unprotected.",
+        "Seien Sie in der Mittagszeit vorsichtig und halten Sie sich nicht zu lange ungeschützt \
+         in der Sonne auf.",
+    ),
+    (
+        "Seek shade during midday hours, cover up and wear sunscreen.",
+        "Suchen Sie mittags Schatten, bedecken Sie sich und tragen Sie Sonnenschutzmittel auf.",
+    ),
+    (
+        "Spend time in the shade between 11am and 3pm. Shirt, sunscreen and hat are essential.",
+        "Bleiben Sie zwischen 11 und 15 Uhr im Schatten. Hemd, Sonnenschutzmittel und Hut sind \
+         unerlässlich.",
+    ),
+    (
+        "Avoid being outside during midday hours. Shirt, sunscreen and hat are essential.",
+        "Vermeiden Sie es, sich mittags im Freien aufzuhalten. Hemd, Sonnenschutzmittel und Hut \
+         sind unerlässlich.",
+    ),
+    // Compass
+    ("NNE", "NNO"),
+    ("NE", "NO"),
+    ("ENE", "ONO"),
+    ("E", "O"),
+    ("ESE", "OSO"),
+    ("SE", "SO"),
+    ("SSE", "SSO"),
+    // Units
+    ("nmi", "sm"),
+];
+
+/// (English, Spanish) pairs
+const SPANISH: &[(&str, &str)] = &[
+    // Conditions
+    ("Trace of rain", "Trazas de lluvia"),
+    ("Clear", "Despejado"),
+    ("Sunny", "Soleado"),
+    ("Partly Cloudy", "Parcialmente nublado"),
+    ("Mist", "Neblina"),
+    ("Fog", "Niebla"),
+    ("Cloudy", "Nublado"),
+    ("Overcast", "Cubierto"),
+    ("Light rain shower", "Chubasco débil"),
+    ("Drizzle", "Llovizna"),
+    ("Light rain", "Lluvia débil"),
+    ("Heavy rain shower", "Chubasco fuerte"),
+    ("Heavy rain", "Lluvia fuerte"),
+    ("Sleet shower", "Chubasco de aguanieve"),
+    ("Sleet", "Aguanieve"),
+    ("Hail shower", "Chubasco de granizo"),
+    ("Hail", "Granizo"),
+    ("Light snow shower", "Chubasco de nieve débil"),
+    ("Light snow", "Nevada débil"),
+    ("Heavy snow shower", "Chubasco de nieve fuerte"),
+    ("Heavy snow", "Nevada fuerte"),
+    ("Thunder shower", "Chubasco tormentoso"),
+    ("Thunder", "Tormenta"),
+    // UV index
+    ("Low", "Bajo"),
+    ("Moderate", "Moderado"),
+    ("High", "Alto"),
+    ("Very high", "Muy alto"),
+    ("Extreme", "Extremo"),
+    (
+        "No protection required. You can safely stay outside.",
+        "No se necesita protección. Puede permanecer al aire libre con seguridad.",
+    ),
+    (
+        "Take care during midday hours and do not spend too much time in the sun unprotected.",
+        "Tenga cuidado en las horas centrales del día y no pase demasiado tiempo al sol sin \
+         protección.",
+    ),
+    (
+        "Seek shade during midday hours, cover up and wear sunscreen.",
+        "Busque la sombra en las horas centrales del día, cúbrase y use protector solar.",
+    ),
+    (
+        "Spend time in the shade between 11am and 3pm. Shirt, sunscreen and hat are essential.",
+        "Permanezca a la sombra entre las 11 y las 15 h. Camisa, protector solar y sombrero son \
+         imprescindibles.",
+    ),
+    (
+        "Avoid being outside during midday hours. Shirt, sunscreen and hat are essential.",
+        "Evite estar al aire libre en las horas centrales del día. Camisa, protector solar y \
+         sombrero son imprescindibles.",
+    ),
+    // Compass
+    ("SSW", "SSO"),
+    ("SW", "SO"),
+    ("WSW", "OSO"),
+    ("W", "O"),
+    ("WNW", "ONO"),
+    ("NW", "NO"),
+    ("NNW", "NNO"),
+    // Units
+    ("in", "pulg"),
+];
@@ -0,0 +1,581 @@
+//! SVG meteogram rendering
+//!
+//! A meteogram shows how the forecast changes over time in a single chart. From top to bottom,
+//! the chart rendered here has:
+//!
+//! - a row of simple icons for the weather conditions;
+//! - temperature (solid red) and feels-like temperature (dashed orange) lines, over
+//!   precipitation bars that are shaded more strongly the more likely precipitation is;
+//! - a trace of the air pressure at mean sea level;
+//! - a row of arrows pointing in the direction the wind is blowing towards, with the wind speed;
+//! - the local time axis.
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! # use rjw_metoffice::meteogram::Options;
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let svg = forecast.to_meteogram(&Options::default());
+//! assert!(svg.starts_with("<svg "));
+//! ```
+//!
+//! The output is a standalone SVG document with no external resources, suitable for embedding in
+//! web pages and emails. The output for a given forecast and options is deterministic.
+//!
+//! For hourly forecasts the precipitation bars show the precipitation rate in mm/hour. For
+//! three-hourly forecasts, which have no instantaneous temperature, the temperature line is the
+//! midpoint of the minimum and maximum temperatures, and the precipitation bars show the total
+//! precipitation over the three hours.
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use jiff::tz::TimeZone;
+
+use crate::units::{Conditions, PressureUnit, SpeedUnit, TemperatureUnit};
+use crate::{Forecast, Hourly, ThreeHourly};
+
+/// Meteogram size, time zone and units
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Width of the SVG in pixels
+    pub width: u32,
+    /// Height of the SVG in pixels
+    pub height: u32,
+    /// Time zone used for the time axis
+    pub time_zone: TimeZone,
+    /// Unit used for the temperature axis
+    pub temperature_unit: TemperatureUnit,
+    /// Unit used for wind speeds
+    pub speed_unit: SpeedUnit,
+    /// Unit used for the pressure trace
+    pub pressure_unit: PressureUnit,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            width: 800,
+            height: 400,
+            time_zone: TimeZone::UTC,
+            temperature_unit: TemperatureUnit::default(),
+            speed_unit: SpeedUnit::default(),
+            pressure_unit: PressureUnit::default(),
+        }
+    }
+}
+
+impl Forecast<Hourly> {
+    /// Render an SVG meteogram of the forecast
+    pub fn to_meteogram(&self, options: &Options) -> String {
+        let points: Vec<Point> = self
+            .predictions
+            .iter()
+            .map(|h| Point {
+                time: h.time.timestamp(),
+                conditions: h.conditions,
+                temperature: options.temperature_unit.convert(h.temperature),
+                feels_like: options.temperature_unit.convert(h.temperature_feels_like),
+                precipitation: h.precipitation_rate.0,
+                precipitation_probability: h.precipitation_probability.0,
+                pressure: options.pressure_unit.convert(h.pressure),
+                wind_speed: options.speed_unit.convert(h.wind_speed),
+                wind_direction: h.wind_direction.0,
+            })
+            .collect();
+        render(&self.location_name, &points, "mm/h", options)
+    }
+}
+
+impl Forecast<ThreeHourly> {
+    /// Render an SVG meteogram of the forecast
+    pub fn to_meteogram(&self, options: &Options) -> String {
+        let points: Vec<Point> = self
+            .predictions
+            .iter()
+            .map(|t| Point {
+                time: t.time.timestamp(),
+                conditions: t.conditions,
+                temperature: (options.temperature_unit.convert(t.temperature_maximum)
+                    + options.temperature_unit.convert(t.temperature_minimum))
+                    / 2.0,
+                feels_like: options.temperature_unit.convert(t.temperature_feels_like),
+                precipitation: t.precipitation_total.0,
+                precipitation_probability: t.precipitation_probability.0,
+                pressure: options.pressure_unit.convert(t.pressure),
+                wind_speed: options.speed_unit.convert(t.wind_speed),
+                wind_direction: t.wind_direction.0,
+            })
+            .collect();
+        render(&self.location_name, &points, "mm", options)
+    }
+}
+
+/// Prediction values converted to display units
+struct Point {
+    time: jiff::Timestamp,
+    conditions: Conditions,
+    temperature: f32,
+    feels_like: f32,
+    precipitation: f32,
+    precipitation_probability: f32,
+    pressure: f32,
+    wind_speed: f32,
+    wind_direction: f32,
+}
+
+/// Vertical extent of a chart panel
+#[derive(Clone, Copy)]
+struct Band {
+    top: f32,
+    height: f32,
+}
+
+impl Band {
+    /// Map `value` within `min..=max` to a y coordinate, with larger values higher up
+    fn y(&self, value: f32, min: f32, max: f32) -> f32 {
+        self.top + self.height * (1.0 - (value - min) / (max - min))
+    }
+
+    fn bottom(&self) -> f32 {
+        self.top + self.height
+    }
+}
+
+const MARGIN_LEFT: f32 = 48.0;
+const MARGIN_RIGHT: f32 = 48.0;
+const ICON_ROW: f32 = 32.0;
+const WIND_ROW: f32 = 36.0;
+const AXIS_ROW: f32 = 28.0;
+const GAP: f32 = 8.0;
+
+const TEMPERATURE_COLOUR: &str = "#d62728";
+const FEELS_LIKE_COLOUR: &str = "#ff7f0e";
+const PRECIPITATION_COLOUR: &str = "#1f77b4";
+const PRESSURE_COLOUR: &str = "#555555";
+const GRID_COLOUR: &str = "#dddddd";
+
+fn render(location_name: &str, points: &[Point], precipitation_unit: &str, o: &Options) -> String {
+    let (width, height) = (o.width as f32, o.height as f32);
+    let mut svg = String::new();
+    // Writing to a String cannot fail.
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+         viewBox=\"0 0 {} {}\" font-family=\"sans-serif\" font-size=\"10\">",
+        o.width, o.height, o.width, o.height
+    );
+    let _ = writeln!(svg, "<title>{}</title>", escape_xml(location_name));
+    let _ = writeln!(
+        svg,
+        "<rect width=\"{}\" height=\"{}\" fill=\"#ffffff\"/>",
+        o.width, o.height
+    );
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        svg.push_str("</svg>\n");
+        return svg;
+    };
+
+    let plot_width = width - MARGIN_LEFT - MARGIN_RIGHT;
+    let span = last.time.duration_since(first.time).as_secs_f32().max(1.0);
+    let x = |time: jiff::Timestamp| {
+        MARGIN_LEFT + plot_width * time.duration_since(first.time).as_secs_f32() / span
+    };
+    let step = plot_width / points.len().max(2).saturating_sub(1) as f32;
+
+    let icons = Band {
+        top: GAP,
+        height: ICON_ROW,
+    };
+    let remaining = height - icons.bottom() - GAP - WIND_ROW - AXIS_ROW - GAP;
+    let main = Band {
+        top: icons.bottom() + GAP,
+        height: remaining * 0.7,
+    };
+    let pressure = Band {
+        top: main.bottom() + GAP,
+        height: remaining * 0.3 - GAP,
+    };
+    let wind = Band {
+        top: pressure.bottom() + GAP,
+        height: WIND_ROW,
+    };
+    let axis = Band {
+        top: wind.bottom(),
+        height: AXIS_ROW,
+    };
+
+    // Conditions icons, spaced at least an icon's width apart.
+    let every = every_nth(step, ICON_ROW);
+    for p in points.iter().step_by(every) {
+        icon(
+            &mut svg,
+            p.conditions,
+            x(p.time),
+            icons.top + icons.height / 2.0,
+        );
+    }
+
+    // Temperature axis and grid lines.
+    let (t_min, t_max) = points
+        .iter()
+        .flat_map(|p| [p.temperature, p.feels_like])
+        .fold((f32::MAX, f32::MIN), |(lo, hi), t| (lo.min(t), hi.max(t)));
+    let t_step = nice_step(t_max - t_min);
+    let t_min = libm::floorf(t_min / t_step) * t_step;
+    let t_max = (libm::floorf(t_max / t_step) + 1.0) * t_step;
+    let mut tick = t_min;
+    while tick <= t_max {
+        let y = main.y(tick, t_min, t_max);
+        let _ = writeln!(
+            svg,
+            "<line x1=\"{MARGIN_LEFT:.1}\" y1=\"{y:.1}\" x2=\"{:.1}\" y2=\"{y:.1}\" stroke=\"{GRID_COLOUR}\"/>",
+            width - MARGIN_RIGHT
+        );
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\" fill=\"{TEMPERATURE_COLOUR}\">{tick:.0}{}</text>",
+            MARGIN_LEFT - 4.0,
+            y + 3.0,
+            o.temperature_unit.symbol()
+        );
+        tick += t_step;
+    }
+
+    // Precipitation bars, on their own axis on the right.
+    let p_max = points.iter().map(|p| p.precipitation).fold(2.0, f32::max);
+    let p_max = libm::ceilf(p_max);
+    let bar_width = (step * 0.8).max(1.0);
+    for p in points.iter().filter(|p| p.precipitation > 0.0) {
+        let top = main.y(p.precipitation, 0.0, p_max);
+        let opacity = (p.precipitation_probability / 100.0).clamp(0.1, 1.0);
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{:.1}\" y=\"{top:.1}\" width=\"{bar_width:.1}\" height=\"{:.1}\" \
+             fill=\"{PRECIPITATION_COLOUR}\" fill-opacity=\"{opacity:.2}\"/>",
+            x(p.time) - bar_width / 2.0,
+            main.bottom() - top,
+        );
+    }
+    for value in [0.0, p_max / 2.0, p_max] {
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.1}\" y=\"{:.1}\" fill=\"{PRECIPITATION_COLOUR}\">{value:.1} {precipitation_unit}</text>",
+            width - MARGIN_RIGHT + 4.0,
+            main.y(value, 0.0, p_max) + 3.0
+        );
+    }
+
+    // Temperature lines are drawn over the bars.
+    polyline(
+        &mut svg,
+        points
+            .iter()
+            .map(|p| (x(p.time), main.y(p.feels_like, t_min, t_max))),
+        FEELS_LIKE_COLOUR,
+        " stroke-dasharray=\"4 3\"",
+    );
+    polyline(
+        &mut svg,
+        points
+            .iter()
+            .map(|p| (x(p.time), main.y(p.temperature, t_min, t_max))),
+        TEMPERATURE_COLOUR,
+        "",
+    );
+
+    // Pressure trace, with a small margin above and below the range.
+    let (pr_min, pr_max) = points
+        .iter()
+        .map(|p| p.pressure)
+        .fold((f32::MAX, f32::MIN), |(lo, hi), p| (lo.min(p), hi.max(p)));
+    let pad = ((pr_max - pr_min) * 0.1).max(match o.pressure_unit {
+        PressureUnit::Hectopascals => 1.0,
+        PressureUnit::InchesOfMercury => 0.03,
+    });
+    let (pr_min, pr_max) = (pr_min - pad, pr_max + pad);
+    let _ = writeln!(
+        svg,
+        "<rect x=\"{MARGIN_LEFT:.1}\" y=\"{:.1}\" width=\"{plot_width:.1}\" height=\"{:.1}\" \
+         fill=\"none\" stroke=\"{GRID_COLOUR}\"/>",
+        pressure.top, pressure.height
+    );
+    polyline(
+        &mut svg,
+        points
+            .iter()
+            .map(|p| (x(p.time), pressure.y(p.pressure, pr_min, pr_max))),
+        PRESSURE_COLOUR,
+        "",
+    );
+    let decimals = match o.pressure_unit {
+        PressureUnit::Hectopascals => 0,
+        PressureUnit::InchesOfMercury => 2,
+    };
+    for (value, y) in [
+        (pr_max - pad, pressure.y(pr_max - pad, pr_min, pr_max)),
+        (pr_min + pad, pressure.y(pr_min + pad, pr_min, pr_max)),
+    ] {
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.1}\" y=\"{:.1}\" fill=\"{PRESSURE_COLOUR}\">{value:.decimals$} {}</text>",
+            width - MARGIN_RIGHT + 4.0,
+            y + 3.0,
+            o.pressure_unit.symbol()
+        );
+    }
+
+    // Wind arrows point downwind, ie away from the direction the wind is blowing from.
+    let every = every_nth(step, 28.0);
+    let arrow_y = wind.top + 12.0;
+    for p in points.iter().step_by(every) {
+        let cx = x(p.time);
+        let _ = writeln!(
+            svg,
+            "<g transform=\"rotate({:.0} {cx:.1} {arrow_y:.1})\"><line x1=\"{cx:.1}\" y1=\"{:.1}\" \
+             x2=\"{cx:.1}\" y2=\"{:.1}\" stroke=\"#333333\" stroke-width=\"1.5\"/><path d=\"M{:.1} {:.1} \
+             L{cx:.1} {:.1} L{:.1} {:.1} Z\" fill=\"#333333\"/></g>",
+            p.wind_direction + 180.0,
+            arrow_y + 8.0,
+            arrow_y - 6.0,
+            cx - 4.0,
+            arrow_y - 4.0,
+            arrow_y - 10.0,
+            cx + 4.0,
+            arrow_y - 4.0,
+        );
+        let _ = writeln!(
+            svg,
+            "<text x=\"{cx:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{:.0}</text>",
+            wind.bottom() - 2.0,
+            p.wind_speed
+        );
+    }
+    let _ = writeln!(
+        svg,
+        "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\" fill=\"#333333\">{}</text>",
+        MARGIN_LEFT - 12.0,
+        wind.bottom() - 2.0,
+        o.speed_unit.symbol()
+    );
+
+    // Local time axis, marking the date at midnight.
+    let every = every_nth(step, 48.0);
+    for p in points.iter().step_by(every) {
+        let local = p.time.to_zoned(o.time_zone.clone());
+        let label = if local.hour() == 0 {
+            local.strftime("%a %d")
+        } else {
+            local.strftime("%H:%M")
+        };
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{label}</text>",
+            x(p.time),
+            axis.top + 14.0
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Draw a line through the given points
+fn polyline(svg: &mut String, points: impl Iterator<Item = (f32, f32)>, colour: &str, extra: &str) {
+    let points: Vec<String> = points.map(|(x, y)| format!("{x:.1},{y:.1}")).collect();
+    let _ = writeln!(
+        svg,
+        "<polyline points=\"{}\" fill=\"none\" stroke=\"{colour}\" stroke-width=\"2\"{extra}/>",
+        points.join(" ")
+    );
+}
+
+/// Draw a small icon for the conditions centred on `(cx, cy)`
+fn icon(svg: &mut String, conditions: Conditions, cx: f32, cy: f32) {
+    use Conditions::*;
+    let sun = |svg: &mut String, r: f32, dx: f32, dy: f32| {
+        let _ = writeln!(
+            svg,
+            "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{r:.1}\" fill=\"#f4c20d\"/>",
+            cx + dx,
+            cy + dy
+        );
+    };
+    let moon = |svg: &mut String, r: f32, dx: f32, dy: f32| {
+        let _ = writeln!(
+            svg,
+            "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{r:.1}\" fill=\"#b0b7c3\"/>",
+            cx + dx,
+            cy + dy
+        );
+    };
+    let cloud = |svg: &mut String, fill: &str| {
+        let _ = writeln!(
+            svg,
+            "<ellipse cx=\"{:.1}\" cy=\"{:.1}\" rx=\"10\" ry=\"6\" fill=\"{fill}\"/>",
+            cx,
+            cy + 1.0
+        );
+    };
+    let drops = |svg: &mut String, colour: &str, n: u8| {
+        for i in 0..n {
+            let dx = (i as f32 - (n - 1) as f32 / 2.0) * 5.0;
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{colour}\" stroke-width=\"1.5\"/>",
+                cx + dx,
+                cy + 8.0,
+                cx + dx - 2.0,
+                cy + 13.0
+            );
+        }
+    };
+    let dots = |svg: &mut String, colour: &str, n: u8| {
+        for i in 0..n {
+            let dx = (i as f32 - (n - 1) as f32 / 2.0) * 5.0;
+            let _ = writeln!(
+                svg,
+                "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"1.6\" fill=\"{colour}\" stroke=\"#888888\" stroke-width=\"0.5\"/>",
+                cx + dx,
+                cy + 11.0
+            );
+        }
+    };
+    let shower_sky = |svg: &mut String, night: bool| {
+        if night {
+            moon(svg, 5.0, -7.0, -5.0);
+        } else {
+            sun(svg, 5.0, -7.0, -5.0);
+        }
+    };
+
+    match conditions {
+        SunnyDay => sun(svg, 8.0, 0.0, 0.0),
+        ClearNight => moon(svg, 8.0, 0.0, 0.0),
+        PartlyCloudyDay | PartlyCloudyNight => {
+            shower_sky(svg, conditions == PartlyCloudyNight);
+            cloud(svg, "#c8ccd2");
+        }
+        Cloudy => cloud(svg, "#c8ccd2"),
+        Overcast => cloud(svg, "#8a9099"),
+        Mist | Fog => {
+            let colour = if conditions == Fog {
+                "#8a9099"
+            } else {
+                "#b0b7c3"
+            };
+            for dy in [-5.0, 0.0, 5.0] {
+                let _ = writeln!(
+                    svg,
+                    "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{colour}\" stroke-width=\"2\"/>",
+                    cx - 10.0,
+                    cy + dy,
+                    cx + 10.0,
+                    cy + dy
+                );
+            }
+        }
+        TraceRain | Drizzle | LightRain | HeavyRain => {
+            cloud(svg, "#8a9099");
+            let n = match conditions {
+                TraceRain | Drizzle => 1,
+                LightRain => 2,
+                _ => 3,
+            };
+            drops(svg, PRECIPITATION_COLOUR, n);
+        }
+        LightRainShowerDay | LightRainShowerNight | HeavyRainShowerDay | HeavyRainShowerNight => {
+            shower_sky(
+                svg,
+                matches!(conditions, LightRainShowerNight | HeavyRainShowerNight),
+            );
+            cloud(svg, "#8a9099");
+            let heavy = matches!(conditions, HeavyRainShowerDay | HeavyRainShowerNight);
+            drops(svg, PRECIPITATION_COLOUR, if heavy { 3 } else { 2 });
+        }
+        Sleet | SleetShowerDay | SleetShowerNight => {
+            if conditions != Sleet {
+                shower_sky(svg, conditions == SleetShowerNight);
+            }
+            cloud(svg, "#8a9099");
+            drops(svg, PRECIPITATION_COLOUR, 1);
+            dots(svg, "#ffffff", 2);
+        }
+        Hail | HailShowerDay | HailShowerNight => {
+            if conditions != Hail {
+                shower_sky(svg, conditions == HailShowerNight);
+            }
+            cloud(svg, "#8a9099");
+            dots(svg, "#d8e6f3", 3);
+        }
+        LightSnow | LightSnowShowerDay | LightSnowShowerNight | HeavySnow | HeavySnowShowerDay
+        | HeavySnowShowerNight => {
+            if !matches!(conditions, LightSnow | HeavySnow) {
+                shower_sky(
+                    svg,
+                    matches!(conditions, LightSnowShowerNight | HeavySnowShowerNight),
+                );
+            }
+            cloud(svg, "#8a9099");
+            let heavy = matches!(
+                conditions,
+                HeavySnow | HeavySnowShowerDay | HeavySnowShowerNight
+            );
+            dots(svg, "#ffffff", if heavy { 3 } else { 2 });
+        }
+        Thunder | ThunderShowerDay | ThunderShowerNight => {
+            if conditions != Thunder {
+                shower_sky(svg, conditions == ThunderShowerNight);
+            }
+            cloud(svg, "#5f6670");
+            let _ = writeln!(
+                svg,
+                "<polyline points=\"{:.1},{:.1} {:.1},{:.1} {:.1},{:.1} {:.1},{:.1}\" fill=\"none\" \
+                 stroke=\"#f4c20d\" stroke-width=\"1.5\"/>",
+                cx + 1.0,
+                cy + 5.0,
+                cx - 2.0,
+                cy + 10.0,
+                cx + 2.0,
+                cy + 10.0,
+                cx - 1.0,
+                cy + 15.0
+            );
+        }
+    }
+}
+
+/// How many points to skip so that drawn items are at least `min_spacing` pixels apart
+fn every_nth(step: f32, min_spacing: f32) -> usize {
+    if step <= 0.0 {
+        return 1;
+    }
+    let mut n = 1;
+    while (n as f32) * step < min_spacing {
+        n += 1;
+    }
+    n
+}
+
+/// Axis tick interval giving at most about six ticks over `range`
+fn nice_step(range: f32) -> f32 {
+    [1.0, 2.0, 5.0, 10.0, 20.0, 50.0]
+        .into_iter()
+        .find(|step| range / step <= 6.0)
+        .unwrap_or(100.0)
+}
+
+/// Escape text for inclusion in SVG element content
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
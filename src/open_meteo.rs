@@ -0,0 +1,310 @@
+//! Open-Meteo-compatible JSON responses
+//!
+//! Hourly and daily forecasts can be rendered in the shape of an [Open-Meteo forecast API]
+//! response, so that front-end code written against Open-Meteo can display Met Office data.
+//! Values are held in parallel arrays under `hourly` or `daily`, with a `time` array giving the
+//! time of each element, and the units of each array given under `hourly_units` or `daily_units`.
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let json = forecast.to_open_meteo();
+//! assert!(json.contains(r#""time":["2023-07-05T10:00","2023-07-05T11:00","#));
+//! ```
+//!
+//! Times are in GMT, as indicated by the `timezone` field. Values are given in this crate's units
+//! (equivalent to Open-Meteo's `wind_speed_unit=ms`), apart from pressure, which is converted to
+//! hectopascals, and snowfall, where the Met Office's liquid water equivalent in millimetres is
+//! given as centimetres of fresh snow. Missing values are `null`.
+//!
+//! The Met Office conditions are mapped to the [WMO weather interpretation codes] used by
+//! Open-Meteo by [`weather_code`].
+//!
+//! [Open-Meteo forecast API]: https://open-meteo.com/en/docs
+//! [WMO weather interpretation codes]: https://open-meteo.com/en/docs#weather_variable_documentation
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use serde::Serialize;
+
+use crate::daily::Day;
+use crate::units::{Conditions, Coordinates, PressureUnit};
+use crate::{Daily, Forecast, Hourly};
+
+/// WMO weather interpretation code for the Met Office conditions
+///
+/// Open-Meteo uses a subset of the WMO codes, so some conditions have only an approximate
+/// equivalent: mist is given as fog (45), sleet as freezing rain (66), and hail as a
+/// thunderstorm with hail (96). Day and night variants share a code.
+pub fn weather_code(conditions: Conditions) -> u8 {
+    use Conditions::*;
+    match conditions {
+        ClearNight | SunnyDay => 0,
+        PartlyCloudyNight | PartlyCloudyDay => 2,
+        Cloudy | Overcast => 3,
+        Mist | Fog => 45,
+        TraceRain => 51,
+        Drizzle => 53,
+        LightRain => 61,
+        HeavyRain => 65,
+        SleetShowerNight | SleetShowerDay | Sleet => 66,
+        LightSnow => 71,
+        HeavySnow => 75,
+        LightRainShowerNight | LightRainShowerDay => 80,
+        HeavyRainShowerNight | HeavyRainShowerDay => 81,
+        LightSnowShowerNight | LightSnowShowerDay => 85,
+        HeavySnowShowerNight | HeavySnowShowerDay => 86,
+        ThunderShowerNight | ThunderShowerDay | Thunder => 95,
+        HailShowerNight | HailShowerDay | Hail => 96,
+    }
+}
+
+/// Top-level response fields shared by hourly and daily responses
+#[derive(Serialize)]
+struct Response<U, V> {
+    latitude: f64,
+    longitude: f64,
+    elevation: f32,
+    generationtime_ms: f32,
+    utc_offset_seconds: i32,
+    timezone: &'static str,
+    timezone_abbreviation: &'static str,
+    #[serde(flatten)]
+    data: Data<U, V>,
+}
+
+#[derive(Serialize)]
+enum Data<U, V> {
+    #[serde(untagged)]
+    Hourly { hourly_units: U, hourly: V },
+    #[serde(untagged)]
+    Daily { daily_units: U, daily: V },
+}
+
+impl<U: Serialize, V: Serialize> Response<U, V> {
+    fn new(coordinates: &Coordinates, data: Data<U, V>) -> Self {
+        Self {
+            latitude: coordinates.latitude.as_float(),
+            longitude: coordinates.longitude.as_float(),
+            elevation: coordinates.altitude.0,
+            generationtime_ms: 0.0,
+            utc_offset_seconds: 0,
+            timezone: "GMT",
+            timezone_abbreviation: "GMT",
+            data,
+        }
+    }
+
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Bug in Open-Meteo serialisation")
+    }
+}
+
+#[derive(Serialize)]
+struct HourlyUnits {
+    time: &'static str,
+    weather_code: &'static str,
+    temperature_2m: &'static str,
+    apparent_temperature: &'static str,
+    dew_point_2m: &'static str,
+    relative_humidity_2m: &'static str,
+    precipitation_probability: &'static str,
+    precipitation: &'static str,
+    snowfall: &'static str,
+    pressure_msl: &'static str,
+    visibility: &'static str,
+    wind_speed_10m: &'static str,
+    wind_direction_10m: &'static str,
+    wind_gusts_10m: &'static str,
+    uv_index: &'static str,
+}
+
+#[derive(Serialize, Default)]
+struct HourlyValues {
+    time: Vec<String>,
+    weather_code: Vec<u8>,
+    temperature_2m: Vec<f32>,
+    apparent_temperature: Vec<f32>,
+    dew_point_2m: Vec<f32>,
+    relative_humidity_2m: Vec<f32>,
+    precipitation_probability: Vec<f32>,
+    precipitation: Vec<Option<f32>>,
+    snowfall: Vec<Option<f32>>,
+    pressure_msl: Vec<f32>,
+    visibility: Vec<f32>,
+    wind_speed_10m: Vec<f32>,
+    wind_direction_10m: Vec<f32>,
+    wind_gusts_10m: Vec<f32>,
+    uv_index: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct DailyUnits {
+    time: &'static str,
+    weather_code: &'static str,
+    temperature_2m_max: &'static str,
+    temperature_2m_min: &'static str,
+    apparent_temperature_max: &'static str,
+    apparent_temperature_min: &'static str,
+    precipitation_probability_max: &'static str,
+    wind_speed_10m_max: &'static str,
+    wind_gusts_10m_max: &'static str,
+    wind_direction_10m_dominant: &'static str,
+    uv_index_max: &'static str,
+}
+
+#[derive(Serialize, Default)]
+struct DailyValues {
+    time: Vec<String>,
+    weather_code: Vec<Option<u8>>,
+    temperature_2m_max: Vec<f32>,
+    temperature_2m_min: Vec<f32>,
+    apparent_temperature_max: Vec<Option<f32>>,
+    apparent_temperature_min: Vec<f32>,
+    precipitation_probability_max: Vec<Option<f32>>,
+    wind_speed_10m_max: Vec<f32>,
+    wind_gusts_10m_max: Vec<f32>,
+    wind_direction_10m_dominant: Vec<f32>,
+    uv_index_max: Vec<Option<u8>>,
+}
+
+impl Forecast<Hourly> {
+    /// Open-Meteo-shaped JSON with `hourly` and `hourly_units` fields
+    pub fn to_open_meteo(&self) -> String {
+        let units = HourlyUnits {
+            time: "iso8601",
+            weather_code: "wmo code",
+            temperature_2m: "°C",
+            apparent_temperature: "°C",
+            dew_point_2m: "°C",
+            relative_humidity_2m: "%",
+            precipitation_probability: "%",
+            precipitation: "mm",
+            snowfall: "cm",
+            pressure_msl: "hPa",
+            visibility: "m",
+            wind_speed_10m: "m/s",
+            wind_direction_10m: "°",
+            wind_gusts_10m: "m/s",
+            uv_index: "",
+        };
+        let mut v = HourlyValues::default();
+        for h in &self.predictions {
+            v.time.push(h.time.strftime("%Y-%m-%dT%H:%M").to_string());
+            v.weather_code.push(weather_code(h.conditions));
+            v.temperature_2m.push(h.temperature.0);
+            v.apparent_temperature.push(h.temperature_feels_like.0);
+            v.dew_point_2m.push(h.dew_point_temperature.0);
+            v.relative_humidity_2m.push(h.relative_humidity.0);
+            v.precipitation_probability
+                .push(h.precipitation_probability.0);
+            v.precipitation.push(h.precipitation_total.map(|p| p.0));
+            v.snowfall.push(h.snow_total.map(|s| s.0));
+            v.pressure_msl
+                .push(PressureUnit::Hectopascals.convert(h.pressure));
+            v.visibility.push(h.visibility.0);
+            v.wind_speed_10m.push(h.wind_speed.0);
+            v.wind_direction_10m.push(h.wind_direction.0);
+            v.wind_gusts_10m.push(h.wind_gust_speed.0);
+            v.uv_index.push(h.uv_index.0);
+        }
+        let data = Data::Hourly {
+            hourly_units: units,
+            hourly: v,
+        };
+        Response::new(&self.coordinates, data).to_json()
+    }
+}
+
+impl Forecast<Daily> {
+    /// Open-Meteo-shaped JSON with `daily` and `daily_units` fields
+    ///
+    /// Daily maxima and minima combine the day and following night. As the Met Office gives wind
+    /// only at midday and midnight, the maximum wind speed and gust are the greater of those two
+    /// values, and the dominant wind direction is the direction at midday.
+    ///
+    /// The first day, which is in the past, is included but has `null` for values the Met Office
+    /// does not provide.
+    pub fn to_open_meteo(&self) -> String {
+        let units = DailyUnits {
+            time: "iso8601",
+            weather_code: "wmo code",
+            temperature_2m_max: "°C",
+            temperature_2m_min: "°C",
+            apparent_temperature_max: "°C",
+            apparent_temperature_min: "°C",
+            precipitation_probability_max: "%",
+            wind_speed_10m_max: "m/s",
+            wind_gusts_10m_max: "m/s",
+            wind_direction_10m_dominant: "°",
+            uv_index_max: "",
+        };
+        let mut v = DailyValues::default();
+        for daily in &self.predictions {
+            let night = &daily.night;
+            v.time.push(daily.time.strftime("%Y-%m-%d").to_string());
+            v.temperature_2m_min
+                .push(night.temperature_minimum.most_likely.0);
+            v.apparent_temperature_min
+                .push(night.temperature_feels_like_minimum.most_likely.0);
+            let (temperature_maximum, wind_speed, wind_gust_speed, wind_direction) =
+                match &daily.day {
+                    Day::Past {
+                        temperature_maximum,
+                        wind_speed,
+                        wind_gust_speed,
+                        wind_direction,
+                        ..
+                    }
+                    | Day::Future {
+                        temperature_maximum,
+                        wind_speed,
+                        wind_gust_speed,
+                        wind_direction,
+                        ..
+                    } => (
+                        temperature_maximum,
+                        wind_speed,
+                        wind_gust_speed,
+                        wind_direction,
+                    ),
+                };
+            v.temperature_2m_max.push(temperature_maximum.most_likely.0);
+            v.wind_speed_10m_max
+                .push(wind_speed.0.max(night.wind_speed.0));
+            v.wind_gusts_10m_max
+                .push(wind_gust_speed.0.max(night.wind_gust_speed.0));
+            v.wind_direction_10m_dominant.push(wind_direction.0);
+            match &daily.day {
+                Day::Past { .. } => {
+                    v.weather_code.push(None);
+                    v.apparent_temperature_max.push(None);
+                    v.precipitation_probability_max.push(None);
+                    v.uv_index_max.push(None);
+                }
+                Day::Future {
+                    conditions,
+                    temperature_feels_like_maximum,
+                    precipitation_probability,
+                    uv_index_maximum,
+                    ..
+                } => {
+                    v.weather_code.push(Some(weather_code(*conditions)));
+                    v.apparent_temperature_max
+                        .push(Some(temperature_feels_like_maximum.most_likely.0));
+                    v.precipitation_probability_max.push(Some(
+                        precipitation_probability
+                            .0
+                            .max(night.precipitation_probability.0),
+                    ));
+                    v.uv_index_max.push(Some(uv_index_maximum.0));
+                }
+            }
+        }
+        let data = Data::Daily {
+            daily_units: units,
+            daily: v,
+        };
+        Response::new(&self.coordinates, data).to_json()
+    }
+}
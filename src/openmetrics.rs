@@ -0,0 +1,242 @@
+//! OpenMetrics (Prometheus) text exposition of forecast values
+//!
+//! Hourly and three-hourly forecasts can be rendered as a set of gauges, one metric family per
+//! prediction field, sampled at a number of lead times after the model run. This is useful for
+//! alerting on forecast values with Prometheus and Grafana.
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! # use rjw_metoffice::openmetrics::DEFAULT_LEAD_TIMES;
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let text = forecast.to_openmetrics(DEFAULT_LEAD_TIMES);
+//! assert!(text.contains("# TYPE metoffice_temperature_celsius gauge"));
+//! assert!(text.ends_with("# EOF\n"));
+//! ```
+//!
+//! Metric names are the prediction field names prefixed with `metoffice_` and suffixed with the
+//! unit, for example `metoffice_wind_speed_meters_per_second`. Every sample is labelled with the
+//! forecast location name, the latitude and longitude of the forecast site, and the lead time.
+//! Fields that are missing from the prediction closest to a lead time are left out.
+//!
+//! With the `std` feature enabled, [`serve`] provides a minimal blocking HTTP endpoint.
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use jiff::SignedDuration;
+
+use crate::fields::{Field, Fields, Unit};
+use crate::{Forecast, Hourly, ThreeHourly, TimePeriod};
+
+/// Lead times used when none are configured: now, and 3, 6, 12 and 24 hours ahead
+pub const DEFAULT_LEAD_TIMES: &[SignedDuration] = &[
+    SignedDuration::ZERO,
+    SignedDuration::from_hours(3),
+    SignedDuration::from_hours(6),
+    SignedDuration::from_hours(12),
+    SignedDuration::from_hours(24),
+];
+
+/// HTTP `Content-Type` header value for the OpenMetrics text format
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+const PREFIX: &str = "metoffice";
+
+impl Forecast<Hourly> {
+    /// Render gauges for the predictions closest to each lead time in OpenMetrics text format
+    ///
+    /// Lead times are measured from the time the model was run (`predictions_made_at`). Lead
+    /// times that fall before the first prediction or beyond the last are skipped.
+    pub fn to_openmetrics(&self, lead_times: &[SignedDuration]) -> String {
+        render(self, lead_times)
+    }
+}
+
+impl Forecast<ThreeHourly> {
+    /// Render gauges for the predictions closest to each lead time in OpenMetrics text format
+    ///
+    /// Lead times are measured from the time the model was run (`predictions_made_at`). Lead
+    /// times that fall before the first prediction or beyond the last are skipped.
+    pub fn to_openmetrics(&self, lead_times: &[SignedDuration]) -> String {
+        render(self, lead_times)
+    }
+}
+
+/// Samples for a single metric family, in lead time order
+struct Family {
+    field: &'static str,
+    unit: Unit,
+    samples: Vec<(SignedDuration, f32)>,
+}
+
+fn render<T: TimePeriod + Fields>(forecast: &Forecast<T>, lead_times: &[SignedDuration]) -> String {
+    let mut families: Vec<Family> = Vec::new();
+    for &lead_time in lead_times {
+        let Some(prediction) = closest_prediction(forecast, lead_time) else {
+            continue;
+        };
+        for Field { name, value } in prediction.fields() {
+            let (unit, value) = (value.unit(), value.as_f32());
+            match families.iter_mut().find(|f| f.field == name) {
+                Some(family) => family.samples.push((lead_time, value)),
+                None => families.push(Family {
+                    field: name,
+                    unit,
+                    samples: alloc::vec![(lead_time, value)],
+                }),
+            }
+        }
+    }
+
+    let labels = format!(
+        "location_name=\"{}\",latitude=\"{}\",longitude=\"{}\"",
+        escape_label_value(&forecast.location_name),
+        forecast.coordinates.latitude.as_float(),
+        forecast.coordinates.longitude.as_float(),
+    );
+
+    let mut out = String::new();
+    for family in families {
+        let name = match unit_suffix(family.unit) {
+            Some(suffix) => format!("{PREFIX}_{}_{suffix}", family.field),
+            None => format!("{PREFIX}_{}", family.field),
+        };
+        // Writing to a String cannot fail.
+        let _ = writeln!(out, "# TYPE {name} gauge");
+        if let Some(suffix) = unit_suffix(family.unit) {
+            let _ = writeln!(out, "# UNIT {name} {suffix}");
+        }
+        for (lead_time, value) in family.samples {
+            let _ = writeln!(
+                out,
+                "{name}{{{labels},lead_time=\"{lead_time:#}\"}} {value}"
+            );
+        }
+    }
+
+    let name = format!("{PREFIX}_predictions_made_at_timestamp_seconds");
+    let _ = writeln!(out, "# TYPE {name} gauge");
+    let _ = writeln!(out, "# UNIT {name} seconds");
+    let _ = writeln!(
+        out,
+        "{name}{{{labels}}} {}",
+        forecast.predictions_made_at.timestamp().as_second()
+    );
+    out.push_str("# EOF\n");
+    out
+}
+
+/// Find the prediction nearest to the given lead time after the model run
+///
+/// Returns `None` if the lead time is outside the span of the predictions, rather than
+/// reporting the first or last prediction for a time it does not cover.
+fn closest_prediction<T: TimePeriod + Fields>(
+    forecast: &Forecast<T>,
+    lead_time: SignedDuration,
+) -> Option<&T> {
+    let target = forecast.predictions_made_at.timestamp() + lead_time;
+    let first = forecast.predictions.first()?;
+    let last = forecast.predictions.last()?;
+    if target < first.time().timestamp() || target > last.time().timestamp() {
+        return None;
+    }
+    forecast
+        .predictions
+        .iter()
+        .min_by_key(|p| p.time().timestamp().duration_since(target).abs())
+}
+
+/// OpenMetrics unit name, which must also be the metric name suffix
+fn unit_suffix(unit: Unit) -> Option<&'static str> {
+    match unit {
+        Unit::Celsius => Some("celsius"),
+        Unit::MetresPerSecond => Some("meters_per_second"),
+        Unit::Degrees => Some("degrees"),
+        Unit::Pascals => Some("pascals"),
+        Unit::Metres => Some("meters"),
+        Unit::Millimetres => Some("millimeters"),
+        Unit::MillimetresPerHour => Some("millimeters_per_hour"),
+        Unit::Percent => Some("percent"),
+        Unit::Index | Unit::Code => None,
+    }
+}
+
+/// Escape backslashes, double quotes and line feeds in a label value
+fn escape_label_value(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// How long [`serve`] waits for a client to send its request
+#[cfg(feature = "std")]
+const READ_TIMEOUT: core::time::Duration = core::time::Duration::from_secs(5);
+
+/// Serve OpenMetrics text over HTTP on `/metrics`
+///
+/// This is a minimal blocking server intended for use as a scrape target. Requests are handled
+/// one at a time, and `scrape` is called for each `GET` or `HEAD` request so that it can return
+/// text for the most recently fetched forecast. Any other path receives a 404 response.
+///
+/// A client that sends nothing is disconnected after a few seconds so that it does not hold up
+/// later scrapes. Failing to accept a connection, for example because the process has run out of
+/// file descriptors, is not fatal, so this never returns.
+#[cfg(feature = "std")]
+pub fn serve<F>(listener: &std::net::TcpListener, mut scrape: F) -> !
+where
+    F: FnMut() -> String,
+{
+    use std::io::{BufRead, BufReader, Write};
+
+    loop {
+        let mut stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(_) => {
+                // Avoid spinning while the error persists.
+                std::thread::sleep(core::time::Duration::from_millis(100));
+                continue;
+            }
+        };
+        if stream.set_read_timeout(Some(READ_TIMEOUT)).is_err() {
+            continue;
+        }
+        let mut reader = BufReader::new(&stream);
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line).is_err() {
+            continue;
+        }
+        // Drain the remaining request headers.
+        let mut header = String::new();
+        while reader.read_line(&mut header).is_ok_and(|n| n > 2) {
+            header.clear();
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let response = match (parts.next(), parts.next()) {
+            (Some(method @ ("GET" | "HEAD")), Some("/metrics")) => {
+                let body = scrape();
+                let mut response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: {CONTENT_TYPE}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                if method == "GET" {
+                    response.push_str(&body);
+                }
+                response
+            }
+            _ => String::from(
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            ),
+        };
+        // A client hanging up early is not a reason to stop serving.
+        let _ = stream.write_all(response.as_bytes());
+    }
+}
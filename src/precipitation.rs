@@ -0,0 +1,213 @@
+//! Detecting spells of precipitation
+//!
+//! Hourly and three-hourly forecasts can be scanned for [`Episode`]s of precipitation, answering
+//! "when will it start raining, and for how long?":
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! use rjw_metoffice::precipitation::EpisodeThresholds;
+//! use rjw_metoffice::units::PrecipitationKind;
+//!
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let episodes = forecast.precipitation_episodes(&EpisodeThresholds::default());
+//! let first = &episodes[0];
+//! assert_eq!(first.kind, PrecipitationKind::Rain);
+//! assert_eq!(first.start.strftime("%H:%M").to_string(), "10:00");
+//! assert_eq!(first.end.strftime("%H:%M").to_string(), "13:00");
+//! ```
+//!
+//! A prediction counts as wet if its probability of precipitation reaches the threshold and
+//! either its precipitation rate reaches the threshold or its conditions are a kind of
+//! precipitation. Wet predictions separated by a short dry spell are joined into one episode.
+//! The thresholds can be tuned with [`EpisodeThresholds`].
+//!
+//! Each prediction is taken to cover the period from its `time` until the next prediction (see
+//! [`TimePeriod::PERIOD`]). Three-hourly forecasts have no precipitation rate, so the mean rate
+//! over each three-hour period is used instead.
+use alloc::vec::Vec;
+
+use jiff::{SignedDuration, Zoned};
+
+use crate::units::{Conditions, Millimetres, MillimetresPerHour, Percentage, PrecipitationKind};
+use crate::{Forecast, Hourly, ThreeHourly, TimePeriod};
+
+/// Thresholds for deciding which predictions are part of an episode of precipitation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EpisodeThresholds {
+    /// Lowest precipitation rate counted as precipitation, by default 0.1 mm/h
+    pub rate: MillimetresPerHour,
+    /// Lowest probability of precipitation for a prediction to count, by default 30%
+    pub probability: Percentage,
+    /// Longest dry spell within a single episode, by default one hour
+    ///
+    /// Wet predictions separated by a dry spell of up to this length are joined into one episode.
+    /// A gap of zero joins only consecutive wet predictions.
+    pub gap: SignedDuration,
+}
+
+impl Default for EpisodeThresholds {
+    fn default() -> Self {
+        Self {
+            rate: MillimetresPerHour(0.1),
+            probability: Percentage(30.0),
+            gap: SignedDuration::from_hours(1),
+        }
+    }
+}
+
+/// An uninterrupted spell of precipitation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Episode {
+    /// Start of the first wet prediction
+    pub start: Zoned,
+    /// End of the last wet prediction
+    pub end: Zoned,
+    /// Highest precipitation rate during the episode
+    pub peak_rate: MillimetresPerHour,
+    /// Total precipitation over the episode, including any dry spells within it
+    pub total: Millimetres,
+    /// Most common kind of precipitation among the wet predictions
+    ///
+    /// Ties go to the kind with the most significant conditions. This is
+    /// [`PrecipitationKind::None`] if precipitation is expected but none of the conditions say
+    /// which kind.
+    pub kind: PrecipitationKind,
+    /// Mean probability of precipitation over the wet predictions
+    pub confidence: Percentage,
+}
+
+impl Episode {
+    /// Length of the episode
+    pub fn duration(&self) -> SignedDuration {
+        self.end.duration_since(&self.start)
+    }
+}
+
+impl Forecast<Hourly> {
+    /// Spells of precipitation in the forecast, in time order
+    pub fn precipitation_episodes(&self, thresholds: &EpisodeThresholds) -> Vec<Episode> {
+        episodes(&self.predictions, thresholds)
+    }
+}
+
+impl Forecast<ThreeHourly> {
+    /// Spells of precipitation in the forecast, in time order
+    pub fn precipitation_episodes(&self, thresholds: &EpisodeThresholds) -> Vec<Episode> {
+        episodes(&self.predictions, thresholds)
+    }
+}
+
+/// Fields of hourly and three-hourly predictions needed to find episodes
+trait Precipitation: TimePeriod {
+    fn conditions(&self) -> Conditions;
+    fn probability(&self) -> f32;
+    /// Precipitation rate in mm/h
+    fn rate(&self) -> f32;
+    /// Precipitation over the prediction's period in mm
+    fn amount(&self) -> f32;
+}
+
+impl Precipitation for Hourly {
+    fn conditions(&self) -> Conditions {
+        self.conditions
+    }
+
+    fn probability(&self) -> f32 {
+        self.precipitation_probability.0
+    }
+
+    fn rate(&self) -> f32 {
+        self.precipitation_rate.0
+    }
+
+    /// The total where given, otherwise the rate over one hour
+    fn amount(&self) -> f32 {
+        self.precipitation_total
+            .map_or(self.precipitation_rate.0, |total| total.0)
+    }
+}
+
+impl Precipitation for ThreeHourly {
+    fn conditions(&self) -> Conditions {
+        self.conditions
+    }
+
+    fn probability(&self) -> f32 {
+        self.precipitation_probability.0
+    }
+
+    fn rate(&self) -> f32 {
+        self.precipitation_total.0 / 3.0
+    }
+
+    fn amount(&self) -> f32 {
+        self.precipitation_total.0
+    }
+}
+
+fn is_wet<T: Precipitation>(prediction: &T, thresholds: &EpisodeThresholds) -> bool {
+    prediction.probability() >= thresholds.probability.0
+        && (prediction.rate() >= thresholds.rate.0
+            || prediction.conditions().precipitation_kind() != PrecipitationKind::None)
+}
+
+fn episodes<T: Precipitation>(predictions: &[T], thresholds: &EpisodeThresholds) -> Vec<Episode> {
+    // Index ranges of wet predictions, joined across short dry spells.
+    let mut spans: Vec<(usize, usize)> = Vec::new();
+    for (i, prediction) in predictions.iter().enumerate() {
+        if !is_wet(prediction, thresholds) {
+            continue;
+        }
+        if let Some((_, last)) = spans.last_mut() {
+            let dry_from = predictions[*last].time().timestamp() + T::PERIOD;
+            let dry = prediction.time().timestamp().duration_since(dry_from);
+            if dry <= thresholds.gap {
+                *last = i;
+                continue;
+            }
+        }
+        spans.push((i, i));
+    }
+
+    spans
+        .into_iter()
+        .map(|(first, last)| {
+            let all = &predictions[first..=last];
+            let wet: Vec<&T> = all.iter().filter(|p| is_wet(*p, thresholds)).collect();
+            Episode {
+                start: predictions[first].time().clone(),
+                end: predictions[last].time() + T::PERIOD,
+                peak_rate: MillimetresPerHour(wet.iter().map(|p| p.rate()).fold(0.0, f32::max)),
+                total: Millimetres(all.iter().map(|p| p.amount()).sum()),
+                kind: dominant_kind(&wet),
+                confidence: Percentage(
+                    wet.iter().map(|p| p.probability()).sum::<f32>() / wet.len() as f32,
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Most common kind of precipitation, breaking ties by the most significant conditions
+fn dominant_kind<T: Precipitation>(predictions: &[&T]) -> PrecipitationKind {
+    // (kind, count, highest severity)
+    let mut kinds: Vec<(PrecipitationKind, usize, u8)> = Vec::new();
+    for prediction in predictions {
+        let conditions = prediction.conditions();
+        let kind = conditions.precipitation_kind();
+        if kind == PrecipitationKind::None {
+            continue;
+        }
+        match kinds.iter_mut().find(|(k, ..)| *k == kind) {
+            Some((_, count, severity)) => {
+                *count += 1;
+                *severity = conditions.severity().max(*severity);
+            }
+            None => kinds.push((kind, 1, conditions.severity())),
+        }
+    }
+    kinds
+        .into_iter()
+        .max_by_key(|&(_, count, severity)| (count, severity))
+        .map_or(PrecipitationKind::None, |(kind, ..)| kind)
+}
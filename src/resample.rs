@@ -0,0 +1,191 @@
+//! Aggregating hourly forecasts over longer windows of time
+//!
+//! An hourly forecast can be resampled into windows of any length, each summarised as a
+//! [`Window`] with temperature extremes and mean, precipitation totals, the strongest gust, the
+//! most significant conditions, and so on. Windows can be given explicitly, as a fixed length,
+//! or as local clock times that divide each day, such as the [`DAY_PARTS`]:
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! use jiff::tz::TimeZone;
+//! use rjw_metoffice::resample::DAY_PARTS;
+//!
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let london = TimeZone::posix("GMT0BST,M3.5.0/1,M10.5.0").unwrap();
+//! let parts = forecast.resample_at(&london, &DAY_PARTS);
+//! let afternoon = &parts[1];
+//! assert_eq!(afternoon.start.strftime("%H:%M").to_string(), "12:00");
+//! assert_eq!(format!("{:.0}", afternoon.temperature_maximum), "19°C");
+//! ```
+//!
+//! Windows at the start or end of the forecast may be only partly covered by predictions; see
+//! [`Window::predictions`].
+use alloc::vec::Vec;
+
+use jiff::civil::Time;
+use jiff::tz::TimeZone;
+use jiff::{SignedDuration, Zoned};
+
+use crate::compass::mean_direction;
+use crate::summary::Extremes;
+use crate::units::{Celsius, Conditions, Degrees, MetresPerSecond, Millimetres, Percentage};
+use crate::{Forecast, Hourly};
+
+/// Local times dividing the day into night, morning, afternoon and evening
+///
+/// Night is from midnight to 06:00, morning to noon, afternoon to 18:00, and evening to midnight.
+pub const DAY_PARTS: [Time; 4] = [
+    Time::constant(0, 0, 0, 0),
+    Time::constant(6, 0, 0, 0),
+    Time::constant(12, 0, 0, 0),
+    Time::constant(18, 0, 0, 0),
+];
+
+/// Hourly predictions aggregated over a window of time
+#[derive(Debug, Clone, PartialEq)]
+pub struct Window {
+    /// Start of the window
+    pub start: Zoned,
+    /// End of the window (exclusive)
+    pub end: Zoned,
+    /// Number of hourly predictions within the window
+    ///
+    /// This is less than the length of the window in hours if the window extends beyond the
+    /// start or end of the forecast.
+    pub predictions: usize,
+    /// Lowest temperature, including the hourly minimum where given
+    pub temperature_minimum: Celsius,
+    /// Highest temperature, including the hourly maximum where given
+    pub temperature_maximum: Celsius,
+    /// Mean of the hourly temperatures
+    pub temperature_mean: Celsius,
+    /// Total precipitation, or `None` if it is missing from any of the hours
+    pub precipitation_total: Option<Millimetres>,
+    /// Total snowfall, or `None` if it is missing from any of the hours
+    pub snow_total: Option<Millimetres>,
+    /// Strongest gust, including the hourly maximum gust where given
+    pub wind_gust_maximum: MetresPerSecond,
+    /// Highest hourly probability of precipitation
+    pub precipitation_probability_maximum: Percentage,
+    /// Most significant conditions, by [`Conditions::severity`]
+    ///
+    /// Where several hours are equally significant, the earliest is used.
+    pub conditions: Conditions,
+    /// Circular mean of the wind direction, or `None` if the directions cancel out
+    pub wind_direction: Option<Degrees>,
+}
+
+impl Window {
+    /// Aggregate the predictions, or `None` if there are none
+    fn new(start: Zoned, end: Zoned, predictions: &[Hourly]) -> Option<Self> {
+        let extremes = Extremes::of(predictions)?;
+        let n = predictions.len();
+        let sum_option = |field: fn(&Hourly) -> Option<Millimetres>| {
+            predictions
+                .iter()
+                .map(field)
+                .try_fold(0.0, |total, p| Some(total + p?.0))
+                .map(Millimetres)
+        };
+        Some(Self {
+            start,
+            end,
+            predictions: n,
+            temperature_minimum: extremes.low,
+            temperature_maximum: extremes.high,
+            temperature_mean: Celsius(
+                predictions.iter().map(|h| h.temperature.0).sum::<f32>() / n as f32,
+            ),
+            precipitation_total: sum_option(|h| h.precipitation_total),
+            snow_total: sum_option(|h| h.snow_total),
+            wind_gust_maximum: extremes.gust,
+            precipitation_probability_maximum: Percentage(
+                predictions
+                    .iter()
+                    .map(|h| h.precipitation_probability.0)
+                    .fold(0.0, f32::max),
+            ),
+            conditions: extremes.conditions,
+            wind_direction: mean_direction(predictions.iter().map(|h| h.wind_direction)),
+        })
+    }
+}
+
+impl Forecast<Hourly> {
+    /// Aggregate the predictions within each of the given windows
+    ///
+    /// Each window is a start time and an exclusive end time, and contains the predictions valid
+    /// at times from the start up to the end. Windows containing no predictions are skipped.
+    pub fn resample(&self, windows: impl IntoIterator<Item = (Zoned, Zoned)>) -> Vec<Window> {
+        windows
+            .into_iter()
+            .filter_map(|(start, end)| {
+                let from = self.predictions.partition_point(|h| h.time < start);
+                let to = self.predictions.partition_point(|h| h.time < end);
+                Window::new(start, end, self.predictions.get(from..to)?)
+            })
+            .collect()
+    }
+
+    /// Aggregate the predictions into consecutive windows of the given length
+    ///
+    /// The first window starts at the first prediction. Returns no windows if the length is
+    /// not positive.
+    pub fn resample_every(&self, length: SignedDuration) -> Vec<Window> {
+        let (Some(first), Some(last)) = (self.predictions.first(), self.predictions.last()) else {
+            return Vec::new();
+        };
+        if !length.is_positive() {
+            return Vec::new();
+        }
+        let mut windows = Vec::new();
+        let mut start = first.time.clone();
+        while start <= last.time {
+            let end = start.saturating_add(length);
+            windows.push((start, end.clone()));
+            start = end;
+        }
+        self.resample(windows)
+    }
+
+    /// Aggregate the predictions into windows that start at the given local times each day
+    ///
+    /// For example, `[00:00, 06:00, 12:00, 18:00]` ([`DAY_PARTS`]) gives night, morning,
+    /// afternoon and evening windows, and each window ends where the next starts. Times are in
+    /// the time zone `tz`, and windows follow clock changes, so an overnight window may be an
+    /// hour shorter or longer than usual. The times need not be in order.
+    pub fn resample_at(&self, tz: &TimeZone, times: &[Time]) -> Vec<Window> {
+        let (Some(first), Some(last)) = (self.predictions.first(), self.predictions.last()) else {
+            return Vec::new();
+        };
+        let mut times = times.to_vec();
+        times.sort();
+        times.dedup();
+        let Some(&earliest) = times.first() else {
+            return Vec::new();
+        };
+
+        // Start from the day before, as the first window may begin on the previous date.
+        let first_date = first.time.with_time_zone(tz.clone()).date();
+        let last_date = last.time.with_time_zone(tz.clone()).date();
+        let mut starts = Vec::new();
+        let mut date = first_date.yesterday().unwrap_or(first_date);
+        while date <= last_date {
+            for time in &times {
+                if let Ok(start) = date.to_datetime(*time).to_zoned(tz.clone()) {
+                    starts.push(start);
+                }
+            }
+            let Ok(next) = date.tomorrow() else { break };
+            date = next;
+        }
+        if let Ok(end) = date.to_datetime(earliest).to_zoned(tz.clone()) {
+            starts.push(end);
+        }
+        self.resample(
+            starts
+                .windows(2)
+                .map(|pair| (pair[0].clone(), pair[1].clone())),
+        )
+    }
+}
@@ -8,13 +8,48 @@ pub trait Sealed: Sized {}
 /// [`Hourly`]: crate::Hourly
 /// [`ThreeHourly`]: crate::ThreeHourly
 /// [`Daily`]: crate::Daily
-pub trait TimePeriod: Sealed {}
+pub trait TimePeriod: Sealed {
+    /// Length of time covered by each prediction, from its `time` to the next prediction
+    const PERIOD: jiff::SignedDuration;
+
+    /// Time at which the prediction is valid
+    fn time(&self) -> &jiff::Zoned;
+
+    /// Calendar date of the prediction in the time zone `tz`
+    fn local_date(&self, tz: &jiff::tz::TimeZone) -> jiff::civil::Date {
+        self.time().with_time_zone(tz.clone()).date()
+    }
+}
 
 impl Sealed for crate::Hourly {}
-impl TimePeriod for crate::Hourly {}
+impl TimePeriod for crate::Hourly {
+    const PERIOD: jiff::SignedDuration = jiff::SignedDuration::from_hours(1);
+
+    fn time(&self) -> &jiff::Zoned {
+        &self.time
+    }
+}
 
 impl Sealed for crate::ThreeHourly {}
-impl TimePeriod for crate::ThreeHourly {}
+impl TimePeriod for crate::ThreeHourly {
+    const PERIOD: jiff::SignedDuration = jiff::SignedDuration::from_hours(3);
+
+    fn time(&self) -> &jiff::Zoned {
+        &self.time
+    }
+}
 
 impl Sealed for crate::Daily {}
-impl TimePeriod for crate::Daily {}
+impl TimePeriod for crate::Daily {
+    const PERIOD: jiff::SignedDuration = jiff::SignedDuration::from_hours(24);
+
+    fn time(&self) -> &jiff::Zoned {
+        &self.time
+    }
+
+    /// Daily predictions are for a whole date, given as midnight UTC, so they fall on the same
+    /// date in every time zone.
+    fn local_date(&self, _tz: &jiff::tz::TimeZone) -> jiff::civil::Date {
+        self.time.date()
+    }
+}
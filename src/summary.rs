@@ -0,0 +1,350 @@
+//! Natural-language forecast summaries
+//!
+//! Hourly and three-hourly forecasts are summarised as a single sentence describing how the
+//! conditions change over a span of time, along with the temperature range and the strongest
+//! gusts. Consecutive predictions with the same description are grouped together.
+//!
+//! ```
+//! # use jiff::{SignedDuration, tz::TimeZone};
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let text = forecast.summary(&TimeZone::UTC, SignedDuration::from_hours(4));
+//! assert_eq!(
+//!     text,
+//!     "Heavy rain this morning, light rain from 11:00, cloudy from 13:00, \
+//!      highs of 19°C, gusts up to 21 mph"
+//! );
+//! ```
+//!
+//! Daily forecasts are summarised one line per day, such as "Tomorrow: sunny, 12–21°C", where
+//! the range runs from the following night's minimum to the day's maximum.
+//!
+//! Times and day names are given in the supplied time zone. Wind speeds are given in miles per
+//! hour, as in Met Office public forecasts.
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use jiff::SignedDuration;
+use jiff::civil::{Date, Weekday};
+use jiff::tz::TimeZone;
+
+use crate::daily::Day;
+use crate::units::{Celsius, Conditions, MetresPerSecond, MilesPerHour};
+use crate::{Daily, Forecast, Hourly, ThreeHourly, TimePeriod};
+
+impl Forecast<Hourly> {
+    /// Summarise the predictions within `span` of the first prediction as a sentence
+    ///
+    /// For example, "Cloudy this morning, heavy rain showers from 14:00, highs of 18°C, gusts up
+    /// to 40 mph". Times are given in the time zone `tz`.
+    pub fn summary(&self, tz: &TimeZone, span: SignedDuration) -> String {
+        summarise(&self.predictions, tz, span)
+    }
+}
+
+impl Forecast<ThreeHourly> {
+    /// Summarise the predictions within `span` of the first prediction as a sentence
+    ///
+    /// For example, "Cloudy this morning, heavy rain showers from 15:00, highs of 18°C, gusts up
+    /// to 40 mph". Times are given in the time zone `tz`.
+    pub fn summary(&self, tz: &TimeZone, span: SignedDuration) -> String {
+        summarise(&self.predictions, tz, span)
+    }
+}
+
+impl Forecast<Daily> {
+    /// Summarise each day from today onwards on a separate line
+    ///
+    /// For example, "Tomorrow: sunny, 12–21°C". "Today" is the date on which the model was run in
+    /// the time zone `tz`. Days that are already in the past are skipped.
+    pub fn summary(&self, tz: &TimeZone) -> Vec<String> {
+        let today = self.predictions_made_at.with_time_zone(tz.clone()).date();
+        self.predictions
+            .iter()
+            .filter_map(|daily| {
+                let Day::Future {
+                    conditions,
+                    temperature_maximum,
+                    ..
+                } = &daily.day
+                else {
+                    return None;
+                };
+                // Daily prediction times are midnight UTC on the date they describe.
+                let date = daily.time.date();
+                if date < today {
+                    return None;
+                }
+                let mut description = conditions.to_string().to_lowercase();
+                if description.ends_with("shower") {
+                    description.push('s');
+                }
+                Some(format!(
+                    "{}: {description}, {:.0}–{:.0}°C",
+                    day_name(date, today),
+                    daily.night.temperature_minimum.most_likely.0,
+                    temperature_maximum.most_likely.0,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Fields of hourly and three-hourly predictions needed for a summary
+pub(crate) trait Summarise: TimePeriod {
+    fn conditions(&self) -> Conditions;
+    fn high(&self) -> f32;
+    fn low(&self) -> f32;
+    fn gust(&self) -> f32;
+}
+
+impl Summarise for Hourly {
+    fn conditions(&self) -> Conditions {
+        self.conditions
+    }
+
+    fn high(&self) -> f32 {
+        let t = self.temperature.0;
+        self.temperature_maximum.map_or(t, |max| max.0.max(t))
+    }
+
+    fn low(&self) -> f32 {
+        let t = self.temperature.0;
+        self.temperature_minimum.map_or(t, |min| min.0.min(t))
+    }
+
+    fn gust(&self) -> f32 {
+        let g = self.wind_gust_speed.0;
+        self.wind_gust_hourly_maximum_speed
+            .map_or(g, |max| max.0.max(g))
+    }
+}
+
+impl Summarise for ThreeHourly {
+    fn conditions(&self) -> Conditions {
+        self.conditions
+    }
+
+    fn high(&self) -> f32 {
+        self.temperature_maximum.0
+    }
+
+    fn low(&self) -> f32 {
+        self.temperature_minimum.0
+    }
+
+    fn gust(&self) -> f32 {
+        self.wind_gust_speed
+            .0
+            .max(self.wind_gust_three_hourly_maximum.0)
+    }
+}
+
+/// Extremes over a run of predictions, shared by day summaries and resampled windows
+pub(crate) struct Extremes {
+    /// Most significant conditions, the earliest of any that are equally significant
+    pub conditions: Conditions,
+    pub low: Celsius,
+    pub high: Celsius,
+    pub gust: MetresPerSecond,
+}
+
+impl Extremes {
+    /// Extremes of the predictions, or `None` if there are none
+    pub(crate) fn of<T: Summarise>(predictions: &[T]) -> Option<Self> {
+        Some(Self {
+            // `max_by_key` picks the last of equals, so search from the end for the earliest.
+            conditions: predictions
+                .iter()
+                .rev()
+                .map(Summarise::conditions)
+                .max_by_key(Conditions::severity)?,
+            low: Celsius(predictions.iter().map(|p| p.low()).fold(f32::MAX, f32::min)),
+            high: Celsius(
+                predictions
+                    .iter()
+                    .map(|p| p.high())
+                    .fold(f32::MIN, f32::max),
+            ),
+            gust: MetresPerSecond(predictions.iter().map(|p| p.gust()).fold(0.0, f32::max)),
+        })
+    }
+}
+
+/// Run of consecutive predictions with similar conditions
+struct Group<'a, T> {
+    similarity: Similarity,
+    predictions: &'a [&'a T],
+}
+
+impl<T: Summarise> Group<'_, T> {
+    /// The most common description within the group, pluralising showers
+    fn description(&self) -> String {
+        let mut best: Option<(String, usize)> = None;
+        for p in self.predictions {
+            let description = p.conditions().to_string();
+            let count = self
+                .predictions
+                .iter()
+                .filter(|other| other.conditions().to_string() == description)
+                .count();
+            if best.as_ref().is_none_or(|(_, n)| count > *n) {
+                best = Some((description, count));
+            }
+        }
+        let (mut description, count) = best.unwrap_or_default();
+        if count > 1 && description.ends_with("shower") {
+            description.push('s');
+        }
+        description
+    }
+}
+
+/// Coarse classes of conditions, used to decide whether neighbouring hours are "similar"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Similarity {
+    Fair,
+    Cloudy,
+    Fog,
+    LightRain,
+    HeavyRain,
+    Sleet,
+    Hail,
+    LightSnow,
+    HeavySnow,
+    Thunder,
+}
+
+impl From<Conditions> for Similarity {
+    fn from(c: Conditions) -> Self {
+        use Conditions::*;
+        match c {
+            ClearNight | SunnyDay | PartlyCloudyNight | PartlyCloudyDay => Self::Fair,
+            Cloudy | Overcast => Self::Cloudy,
+            Mist | Fog => Self::Fog,
+            TraceRain | Drizzle | LightRain | LightRainShowerNight | LightRainShowerDay => {
+                Self::LightRain
+            }
+            HeavyRain | HeavyRainShowerNight | HeavyRainShowerDay => Self::HeavyRain,
+            Sleet | SleetShowerNight | SleetShowerDay => Self::Sleet,
+            Hail | HailShowerNight | HailShowerDay => Self::Hail,
+            LightSnow | LightSnowShowerNight | LightSnowShowerDay => Self::LightSnow,
+            HeavySnow | HeavySnowShowerNight | HeavySnowShowerDay => Self::HeavySnow,
+            Thunder | ThunderShowerNight | ThunderShowerDay => Self::Thunder,
+        }
+    }
+}
+
+/// Split predictions into runs of similar conditions
+///
+/// A single prediction that interrupts a run (for example one cloudy hour in a clear night) is
+/// absorbed into the surrounding run.
+fn group<'a, T: Summarise>(predictions: &'a [&'a T]) -> Vec<Group<'a, T>> {
+    let mut groups: Vec<Group<'a, T>> = Vec::new();
+    let mut start = 0;
+    for (i, p) in predictions.iter().enumerate() {
+        let similarity = Similarity::from(p.conditions());
+        let continues = groups.last().is_some_and(|g| g.similarity == similarity);
+        if continues {
+            let g = groups.last_mut().unwrap();
+            g.predictions = &predictions[start..=i];
+            continue;
+        }
+        // Absorb a lone interruption between two runs of the same kind.
+        if let [.., before, blip] = groups.as_slice()
+            && blip.predictions.len() == 1
+            && before.similarity == similarity
+        {
+            groups.pop();
+            let g = groups.last_mut().unwrap();
+            start = i - g.predictions.len() - 1;
+            g.predictions = &predictions[start..=i];
+            continue;
+        }
+        start = i;
+        groups.push(Group {
+            similarity,
+            predictions: &predictions[i..=i],
+        });
+    }
+    groups
+}
+
+fn summarise<T: Summarise>(predictions: &[T], tz: &TimeZone, span: SignedDuration) -> String {
+    let Some(first) = predictions.first() else {
+        return String::new();
+    };
+    let end = first.time().timestamp() + span;
+    let predictions: Vec<&T> = predictions
+        .iter()
+        .take_while(|p| p.time().timestamp() < end)
+        .collect();
+
+    let groups = group(&predictions);
+    let first_date = first.time().with_time_zone(tz.clone()).date();
+    let mut parts: Vec<String> = Vec::new();
+    for (i, group) in groups.iter().enumerate() {
+        let start = group.predictions[0].time().with_time_zone(tz.clone());
+        let description = group.description();
+        if i == 0 {
+            parts.push(format!("{description} {}", day_part(&start)));
+            continue;
+        }
+        let time = start.strftime("%H:%M");
+        let description = description.to_lowercase();
+        parts.push(match day_name(start.date(), first_date) {
+            "Today" => format!("{description} from {time}"),
+            "Tomorrow" => format!("{description} from {time} tomorrow"),
+            weekday => format!("{description} from {time} on {weekday}"),
+        });
+    }
+
+    let high = predictions
+        .iter()
+        .map(|p| p.high())
+        .fold(f32::MIN, f32::max);
+    parts.push(format!("highs of {high:.0}°C"));
+    let spans_night = predictions
+        .iter()
+        .any(|p| matches!(p.time().with_time_zone(tz.clone()).hour(), 22.. | ..5));
+    if spans_night {
+        let low = predictions.iter().map(|p| p.low()).fold(f32::MAX, f32::min);
+        parts.push(format!("lows of {low:.0}°C"));
+    }
+    let gust = predictions.iter().map(|p| p.gust()).fold(0.0, f32::max);
+    parts.push(format!(
+        "gusts up to {:.0}",
+        MilesPerHour::from(MetresPerSecond(gust))
+    ));
+    parts.join(", ")
+}
+
+/// Describe the part of the day containing `time`, such as "this morning"
+fn day_part(time: &jiff::Zoned) -> &'static str {
+    match time.hour() {
+        5..12 => "this morning",
+        12..18 => "this afternoon",
+        18..22 => "this evening",
+        _ => "tonight",
+    }
+}
+
+/// Name a date relative to today, such as "Tomorrow" or "Saturday"
+fn day_name(date: Date, today: Date) -> &'static str {
+    if date == today {
+        return "Today";
+    }
+    if Some(date) == today.tomorrow().ok() {
+        return "Tomorrow";
+    }
+    match date.weekday() {
+        Weekday::Monday => "Monday",
+        Weekday::Tuesday => "Tuesday",
+        Weekday::Wednesday => "Wednesday",
+        Weekday::Thursday => "Thursday",
+        Weekday::Friday => "Friday",
+        Weekday::Saturday => "Saturday",
+        Weekday::Sunday => "Sunday",
+    }
+}
@@ -0,0 +1,202 @@
+//! Quantities derived from temperature, humidity, pressure and wind
+//!
+//! Hourly forecasts give the air temperature, dew point and relative humidity, from which other
+//! measures of humidity and comfort can be calculated. Each function cites the formula it uses,
+//! and the same calculations are available as methods on [`Hourly`]:
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! use rjw_metoffice::thermodynamics::humidex;
+//! use rjw_metoffice::units::Celsius;
+//!
+//! assert_eq!(format!("{:.0}", humidex(Celsius(30.0), Celsius(15.0))), "34°C");
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let wet_bulb = forecast.predictions[0].wet_bulb_temperature();
+//! ```
+//!
+//! These are empirical approximations, accurate to a few tenths of a degree or percent over the
+//! range of conditions usually met at the surface, and are not suitable for extreme conditions.
+use crate::Hourly;
+use crate::units::{
+    Celsius, GramsPerCubicMetre, GramsPerKilogram, Hectopascals, KilometresPerHour, Metres,
+    MetresPerSecond, Pascals, Percentage,
+};
+
+/// Specific gas constant for water vapour, in J/(kg·K)
+const WATER_VAPOUR_GAS_CONSTANT: f32 = 461.5;
+
+/// Ratio of the molar masses of water vapour and dry air
+const MOLAR_MASS_RATIO: f32 = 0.622;
+
+/// Saturation vapour pressure over water at the given temperature
+///
+/// Uses the Magnus formula with the coefficients recommended by Alduchov and Eskridge (1996),
+/// "Improved Magnus Form Approximation of Saturation Vapor Pressure", Journal of Applied
+/// Meteorology 35(4):
+///
+/// e<sub>s</sub> = 6.1094 exp(17.625 T / (T + 243.04)) hPa
+pub fn saturation_vapour_pressure(temperature: Celsius) -> Hectopascals {
+    let t = temperature.0;
+    Hectopascals(6.1094 * libm::expf(17.625 * t / (t + 243.04)))
+}
+
+/// Actual vapour pressure of air with the given dew point
+///
+/// This is the saturation vapour pressure at the dew point (see [`saturation_vapour_pressure`]).
+pub fn vapour_pressure(dew_point: Celsius) -> Hectopascals {
+    saturation_vapour_pressure(dew_point)
+}
+
+/// Mass of water vapour per cubic metre of air
+///
+/// From the ideal gas law for water vapour, ρ<sub>v</sub> = e / (R<sub>v</sub> T), with the
+/// vapour pressure e from the dew point and R<sub>v</sub> = 461.5 J/(kg·K).
+pub fn absolute_humidity(temperature: Celsius, dew_point: Celsius) -> GramsPerCubicMetre {
+    let e = vapour_pressure(dew_point).0 * 100.0;
+    let kelvin = temperature.0 + 273.15;
+    GramsPerCubicMetre(1000.0 * e / (WATER_VAPOUR_GAS_CONSTANT * kelvin))
+}
+
+/// Mass of water vapour per kilogram of dry air
+///
+/// Uses w = 0.622 e / (p − e), where e is the vapour pressure from the dew point and p is the
+/// air pressure (Wallace and Hobbs, "Atmospheric Science", 2nd ed., eq. 3.57). The Met Office
+/// gives pressure at mean sea level, which overestimates the mixing ratio slightly at altitude.
+pub fn mixing_ratio(dew_point: Celsius, pressure: Pascals) -> GramsPerKilogram {
+    let e = vapour_pressure(dew_point).0;
+    let p = Hectopascals::from(pressure).0;
+    GramsPerKilogram(1000.0 * MOLAR_MASS_RATIO * e / (p - e))
+}
+
+/// Wet-bulb temperature from the air temperature and relative humidity
+///
+/// Uses the empirical formula of Stull (2011), "Wet-Bulb Temperature from Relative Humidity and
+/// Air Temperature", Journal of Applied Meteorology and Climatology 50(11), which is valid at
+/// standard sea-level pressure for relative humidities from 5% to 99% and temperatures from
+/// −20°C to 50°C.
+pub fn wet_bulb_temperature(temperature: Celsius, relative_humidity: Percentage) -> Celsius {
+    let t = temperature.0;
+    let rh = relative_humidity.0;
+    Celsius(
+        t * libm::atanf(0.151_977 * libm::sqrtf(rh + 8.313_659)) + libm::atanf(t + rh)
+            - libm::atanf(rh - 1.676_331)
+            + 0.003_918_38 * libm::powf(rh, 1.5) * libm::atanf(0.023_101 * rh)
+            - 4.686_035,
+    )
+}
+
+/// Heat index (apparent temperature in hot, humid conditions)
+///
+/// Follows the US National Weather Service procedure: Steadman's simple formula is used when the
+/// heat index is below 80°F (26.7°C), otherwise the Rothfusz (1990) regression, with the NWS
+/// adjustments for very low and very high humidity. See
+/// <https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml>.
+pub fn heat_index(temperature: Celsius, relative_humidity: Percentage) -> Celsius {
+    let t = temperature.0 * 9.0 / 5.0 + 32.0;
+    let rh = relative_humidity.0;
+
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    let hi = if (simple + t) / 2.0 < 80.0 {
+        simple
+    } else {
+        let mut hi = -42.379 + 2.049_015_2 * t + 10.143_331 * rh
+            - 0.224_755_4 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh;
+        if rh < 13.0 && (80.0..=112.0).contains(&t) {
+            hi -= (13.0 - rh) / 4.0 * libm::sqrtf((17.0 - libm::fabsf(t - 95.0)) / 17.0);
+        } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+            hi += (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0);
+        }
+        hi
+    };
+    Celsius((hi - 32.0) * 5.0 / 9.0)
+}
+
+/// Humidex, the Canadian measure of how hot humid weather feels
+///
+/// Uses the formula of Masterton and Richardson (1979), "Humidex: A method of quantifying human
+/// discomfort due to excessive heat and humidity", Environment Canada, as used by the
+/// Meteorological Service of Canada:
+///
+/// H = T + 0.5555 (6.11 exp(5417.7530 (1/273.16 − 1/T<sub>d</sub>)) − 10)
+///
+/// where the dew point T<sub>d</sub> is in kelvin.
+pub fn humidex(temperature: Celsius, dew_point: Celsius) -> Celsius {
+    let dew_point_kelvin = dew_point.0 + 273.15;
+    let e = 6.11 * libm::expf(5417.753 * (1.0 / 273.16 - 1.0 / dew_point_kelvin));
+    Celsius(temperature.0 + 0.5555 * (e - 10.0))
+}
+
+/// North American wind chill index (JAG/TI 2001)
+///
+/// Uses the formula adopted by Environment Canada and the US National Weather Service in 2001:
+///
+/// W = 13.12 + 0.6215 T − 11.37 V<sup>0.16</sup> + 0.3965 T V<sup>0.16</sup>
+///
+/// where V is the wind speed at 10m in km/h. Returns `None` outside the range in which the index
+/// is defined: temperatures above 10°C or wind speeds below 4.8 km/h.
+pub fn wind_chill(temperature: Celsius, wind_speed: MetresPerSecond) -> Option<Celsius> {
+    let t = temperature.0;
+    let v = KilometresPerHour::from(wind_speed).0;
+    if t > 10.0 || v < 4.8 {
+        return None;
+    }
+    let v = libm::powf(v, 0.16);
+    Some(Celsius(13.12 + 0.6215 * t - 11.37 * v + 0.3965 * t * v))
+}
+
+/// Estimated height of the cloud base above ground level
+///
+/// Uses Espy's approximation for convective cloud, 125m per degree Celsius of difference between
+/// the air temperature and dew point (the lifting condensation level of a surface parcel), as
+/// described in the WMO "International Meteorological Vocabulary". It is a poor estimate for
+/// cloud that has not formed from surface air, such as frontal cloud.
+pub fn cloud_base(temperature: Celsius, dew_point: Celsius) -> Metres {
+    Metres(125.0 * (temperature.0 - dew_point.0).max(0.0))
+}
+
+impl Hourly {
+    /// Actual vapour pressure, from the dew point
+    pub fn vapour_pressure(&self) -> Hectopascals {
+        vapour_pressure(self.dew_point_temperature)
+    }
+
+    /// Mass of water vapour per cubic metre of air
+    pub fn absolute_humidity(&self) -> GramsPerCubicMetre {
+        absolute_humidity(self.temperature, self.dew_point_temperature)
+    }
+
+    /// Mass of water vapour per kilogram of dry air, using the mean sea level pressure
+    pub fn mixing_ratio(&self) -> GramsPerKilogram {
+        mixing_ratio(self.dew_point_temperature, self.pressure)
+    }
+
+    /// Wet-bulb temperature, from the temperature and relative humidity
+    pub fn wet_bulb_temperature(&self) -> Celsius {
+        wet_bulb_temperature(self.temperature, self.relative_humidity)
+    }
+
+    /// Heat index, from the temperature and relative humidity
+    pub fn heat_index(&self) -> Celsius {
+        heat_index(self.temperature, self.relative_humidity)
+    }
+
+    /// Humidex, from the temperature and dew point
+    pub fn humidex(&self) -> Celsius {
+        humidex(self.temperature, self.dew_point_temperature)
+    }
+
+    /// Wind chill index, or `None` if it is too warm or too calm for wind chill
+    pub fn wind_chill(&self) -> Option<Celsius> {
+        wind_chill(self.temperature, self.wind_speed)
+    }
+
+    /// Estimated cloud base above ground level, from the temperature and dew point
+    pub fn cloud_base(&self) -> Metres {
+        cloud_base(self.temperature, self.dew_point_temperature)
+    }
+}
@@ -1,8 +1,8 @@
 use crate::Error;
 use crate::parse::RawThreeHourlyForecast;
 use crate::units::{
-    Celsius, Conditions, Degrees, Metres, MetresPerSecond, Millimetres, Pascals, Percentage,
-    UvIndex,
+    Celsius, Conditions, Degrees, MetresPerSecond, Millimetres, Pascals, Percentage, UvIndex,
+    Visibility,
 };
 
 /// Forecast for a three-hour period
@@ -66,7 +66,7 @@ pub struct ThreeHourly {
     /// Most extreme wind speed that might be experienced in this period.
     pub wind_gust_three_hourly_maximum: MetresPerSecond,
     /// Distance in metres at which a known object can be seen horizontally from screen level (1.5m).
-    pub visibility: Metres,
+    pub visibility: Visibility,
     /// Percent relative humidity at screen level (1.5m).
     pub relative_humidity: Percentage,
     /// Air pressure at mean sea level in Pascals.
@@ -125,7 +125,7 @@ impl TryFrom<RawThreeHourlyForecast> for ThreeHourly {
             wind_direction: Degrees(rf.wind_direction_from_10m),
             wind_gust_speed: MetresPerSecond(rf.wind_gust_speed_10m),
             wind_gust_three_hourly_maximum: MetresPerSecond(rf.max_10m_wind_gust),
-            visibility: Metres(rf.visibility),
+            visibility: Visibility(rf.visibility),
             relative_humidity: Percentage(rf.screen_relative_humidity),
             pressure: Pascals(rf.mslp),
             uv_index: UvIndex(rf.uv_index),
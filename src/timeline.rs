@@ -0,0 +1,218 @@
+//! A single timeline combining hourly, three-hourly and daily forecasts
+//!
+//! The Global Spot API gives hourly predictions for 48 hours, three-hourly predictions for 7
+//! days, and daily day and night predictions for 7 days. A [`Timeline`] joins forecasts for the
+//! same site into one sequence of [`Point`]s, using the finest resolution available at each time:
+//! hourly for the first 48 hours, then three-hourly out to 7 days, then daily for anything beyond.
+//!
+//! ```
+//! # use rjw_metoffice::{Daily, Forecast, Hourly, ThreeHourly};
+//! use rjw_metoffice::timeline::{Resolution, Timeline};
+//!
+//! # fn example(
+//! #     hourly: Forecast<Hourly>,
+//! #     three_hourly: Forecast<ThreeHourly>,
+//! #     daily: Forecast<Daily>,
+//! # ) -> Result<(), rjw_metoffice::Error> {
+//! let timeline = Timeline::new(hourly, three_hourly, daily)?;
+//! for point in timeline.points() {
+//!     println!("{}    {}", point.time(), point.resolution());
+//! }
+//! if timeline.model_run_spread() > jiff::SignedDuration::from_hours(6) {
+//!     println!("Forecasts are from model runs more than six hours apart");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Each forecast comes from a separate request, so they may be from different model runs; see
+//! [`Timeline::model_run_spread`].
+use jiff::{SignedDuration, Timestamp, Zoned};
+
+use crate::daily::Day;
+use crate::units::Metres;
+use crate::{Daily, Error, Forecast, Hourly, ThreeHourly, TimePeriod};
+
+/// How far from the start of the timeline hourly predictions are used
+pub const HOURLY_HORIZON: SignedDuration = SignedDuration::from_hours(48);
+
+/// How far from the start of the timeline three-hourly predictions are used
+pub const THREE_HOURLY_HORIZON: SignedDuration = SignedDuration::from_hours(7 * 24);
+
+/// Greatest distance between the forecast sites for them to count as the same site
+///
+/// Site coordinates are given to three decimal places, about 100 metres.
+const SAME_SITE_DISTANCE: Metres = Metres(100.0);
+
+/// Time resolution of the forecast a point was taken from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Resolution {
+    Hourly,
+    ThreeHourly,
+    Daily,
+}
+
+impl Resolution {
+    /// Length of time covered by each prediction at this resolution
+    pub fn period(self) -> SignedDuration {
+        match self {
+            Resolution::Hourly => Hourly::PERIOD,
+            Resolution::ThreeHourly => ThreeHourly::PERIOD,
+            Resolution::Daily => Daily::PERIOD,
+        }
+    }
+}
+
+impl core::fmt::Display for Resolution {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Resolution::Hourly => "hourly",
+            Resolution::ThreeHourly => "three-hourly",
+            Resolution::Daily => "daily",
+        })
+    }
+}
+
+/// A prediction in the timeline, tagged with its source resolution
+#[derive(Debug, Clone, Copy)]
+pub enum Point<'a> {
+    Hourly(&'a Hourly),
+    ThreeHourly(&'a ThreeHourly),
+    Daily(&'a Daily),
+}
+
+impl<'a> Point<'a> {
+    /// Time at which the prediction is valid
+    pub fn time(&self) -> &'a Zoned {
+        match self {
+            Point::Hourly(p) => &p.time,
+            Point::ThreeHourly(p) => &p.time,
+            Point::Daily(p) => &p.time,
+        }
+    }
+
+    /// Resolution of the forecast the prediction was taken from
+    pub fn resolution(&self) -> Resolution {
+        match self {
+            Point::Hourly(_) => Resolution::Hourly,
+            Point::ThreeHourly(_) => Resolution::ThreeHourly,
+            Point::Daily(_) => Resolution::Daily,
+        }
+    }
+}
+
+/// Hourly, three-hourly and daily forecasts for the same site
+#[derive(Debug)]
+pub struct Timeline {
+    pub hourly: Forecast<Hourly>,
+    pub three_hourly: Forecast<ThreeHourly>,
+    pub daily: Forecast<Daily>,
+}
+
+impl Timeline {
+    /// Combine forecasts for a single site
+    ///
+    /// Returns [`Error::DifferentForecastSites`] if the forecasts' coordinates are more than
+    /// 100 metres apart.
+    pub fn new(
+        hourly: Forecast<Hourly>,
+        three_hourly: Forecast<ThreeHourly>,
+        daily: Forecast<Daily>,
+    ) -> Result<Self, Error> {
+        let site = hourly.coordinates;
+        let same_site = [three_hourly.coordinates, daily.coordinates]
+            .iter()
+            .all(|other| site.distance_to(other).0 <= SAME_SITE_DISTANCE.0);
+        if !same_site {
+            return Err(Error::DifferentForecastSites);
+        }
+        Ok(Self {
+            hourly,
+            three_hourly,
+            daily,
+        })
+    }
+
+    /// Time between the earliest and latest of the forecasts' model runs
+    pub fn model_run_spread(&self) -> SignedDuration {
+        let runs = [
+            self.hourly.predictions_made_at.timestamp(),
+            self.three_hourly.predictions_made_at.timestamp(),
+            self.daily.predictions_made_at.timestamp(),
+        ];
+        let earliest = runs.iter().min().copied().unwrap_or_default();
+        let latest = runs.iter().max().copied().unwrap_or_default();
+        latest.duration_since(earliest)
+    }
+
+    /// Predictions in time order, at the finest resolution available
+    ///
+    /// Hourly predictions are used for up to [`HOURLY_HORIZON`] from the first prediction, and
+    /// three-hourly predictions up to [`THREE_HOURLY_HORIZON`]. The switch to three-hourly
+    /// predictions is made at the start of the three-hourly period in which the hourly
+    /// predictions run out, so that there is no gap between them. Daily predictions are used
+    /// only for days that start after the last three-hourly period ends.
+    pub fn points(&self) -> impl Iterator<Item = Point<'_>> {
+        let hourly = &self.hourly.predictions;
+        let three_hourly = &self.three_hourly.predictions;
+        let start = hourly
+            .first()
+            .map(timestamp)
+            .or_else(|| three_hourly.first().map(timestamp))
+            .unwrap_or(Timestamp::MIN);
+
+        let hourly_end = hourly
+            .last()
+            .map_or(start, |h| timestamp(h) + Hourly::PERIOD)
+            .min(
+                start
+                    .saturating_add(HOURLY_HORIZON)
+                    .unwrap_or(Timestamp::MAX),
+            );
+        let i = three_hourly.partition_point(|t| timestamp(t) <= hourly_end);
+        let switch = i
+            .checked_sub(1)
+            .map(|i| timestamp(&three_hourly[i]))
+            .filter(|&t| t > start)
+            .unwrap_or(hourly_end);
+
+        let three_hourly_end = start
+            .saturating_add(THREE_HOURLY_HORIZON)
+            .unwrap_or(Timestamp::MAX);
+        let three_hourly: &[ThreeHourly] = {
+            let from = three_hourly.partition_point(|t| timestamp(t) < switch);
+            let to = three_hourly.partition_point(|t| timestamp(t) < three_hourly_end);
+            three_hourly.get(from..to).unwrap_or_default()
+        };
+        let daily_start = three_hourly
+            .last()
+            .map_or(switch, |t| timestamp(t) + ThreeHourly::PERIOD);
+
+        hourly
+            .iter()
+            .take_while(move |h| timestamp(*h) < switch)
+            .map(Point::Hourly)
+            .chain(three_hourly.iter().map(Point::ThreeHourly))
+            .chain(
+                self.daily
+                    .predictions
+                    .iter()
+                    .filter(move |d| timestamp(*d) >= daily_start)
+                    .map(Point::Daily),
+            )
+    }
+
+    /// Daily predictions for days that have not yet passed, for day and night summaries
+    ///
+    /// The first daily prediction is for the day before the model run and is skipped.
+    pub fn days(&self) -> impl Iterator<Item = &Daily> {
+        self.daily
+            .predictions
+            .iter()
+            .filter(|d| matches!(d.day, Day::Future { .. }))
+    }
+}
+
+fn timestamp<T: TimePeriod>(prediction: &T) -> Timestamp {
+    prediction.time().timestamp()
+}
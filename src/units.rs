@@ -1,11 +1,14 @@
 //! Newtype wrappers for forecast units
+use alloc::format;
+use alloc::string::String;
+
 use serde::Deserialize;
 
 use crate::Error;
 
 /// Latitude in decimal degrees in the WGS 84 reference system
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
-pub struct Latitude(f64);
+pub struct Latitude(pub(crate) f64);
 
 impl Latitude {
     /// Construct a latitude from a float
@@ -35,15 +38,16 @@ impl TryFrom<f64> for Latitude {
 
 impl core::fmt::Display for Latitude {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let c = if self.0.is_sign_positive() { 'N' } else { 'S' };
+        let c = if self.0.is_sign_negative() { 'S' } else { 'N' };
         let d = self.0.abs();
-        write!(f, "{d:.3}° {c}")
+        let p = f.precision().unwrap_or(3);
+        write!(f, "{d:.p$}° {c}")
     }
 }
 
-/// Latitude in decimal degrees in the WGS 84 reference system
+/// Longitude in decimal degrees in the WGS 84 reference system
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
-pub struct Longitude(f64);
+pub struct Longitude(pub(crate) f64);
 
 impl Longitude {
     /// Construct a longitude from a float
@@ -73,9 +77,10 @@ impl TryFrom<f64> for Longitude {
 
 impl core::fmt::Display for Longitude {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let c = if self.0.is_sign_positive() { 'W' } else { 'E' };
+        let c = if self.0.is_sign_negative() { 'W' } else { 'E' };
         let d = self.0.abs();
-        write!(f, "{d:.3}° {c}")
+        let p = f.precision().unwrap_or(3);
+        write!(f, "{d:.p$}° {c}")
     }
 }
 
@@ -115,7 +120,8 @@ pub struct Percentage(pub f32);
 
 impl core::fmt::Display for Percentage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:.0}%", self.0)
+        let p = f.precision().unwrap_or(0);
+        write!(f, "{:.*}%", p, self.0)
     }
 }
 
@@ -124,7 +130,8 @@ pub struct Metres(pub f32);
 
 impl core::fmt::Display for Metres {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:.0}m", self.0)
+        let p = f.precision().unwrap_or(0);
+        write!(f, "{:.*}m", p, self.0)
     }
 }
 
@@ -133,7 +140,8 @@ pub struct MetresPerSecond(pub f32);
 
 impl core::fmt::Display for MetresPerSecond {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:.2} m/s", self.0)
+        let p = f.precision().unwrap_or(2);
+        write!(f, "{:.*} m/s", p, self.0)
     }
 }
 
@@ -142,7 +150,8 @@ pub struct Millimetres(pub f32);
 
 impl core::fmt::Display for Millimetres {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:.2} mm", self.0)
+        let p = f.precision().unwrap_or(2);
+        write!(f, "{:.*} mm", p, self.0)
     }
 }
 
@@ -151,7 +160,8 @@ pub struct MillimetresPerHour(pub f32);
 
 impl core::fmt::Display for MillimetresPerHour {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:.2} mm/hour", self.0)
+        let p = f.precision().unwrap_or(2);
+        write!(f, "{:.*} mm/hour", p, self.0)
     }
 }
 
@@ -160,7 +170,8 @@ pub struct Celsius(pub f32);
 
 impl core::fmt::Display for Celsius {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:.2}°C", self.0)
+        let p = f.precision().unwrap_or(2);
+        write!(f, "{:.*}°C", p, self.0)
     }
 }
 
@@ -173,6 +184,581 @@ impl core::fmt::Display for Pascals {
     }
 }
 
+/// Define a converted unit newtype with a precision-aware `Display`
+///
+/// Values are displayed with `precision` decimal places, unless a precision is given in the format
+/// string, such as `{:.0}`.
+macro_rules! unit {
+    ($(#[$attr:meta])* $name:ident, $symbol:literal, $precision:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        pub struct $name(pub f32);
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let p = f.precision().unwrap_or($precision);
+                write!(f, "{:.*}{}", p, self.0, $symbol)
+            }
+        }
+    };
+}
+
+/// Implement `From` in both directions between two `f32` unit newtypes
+macro_rules! convert {
+    ($from:ident => $to:ident, |$x:ident| $forward:expr, |$y:ident| $back:expr) => {
+        impl From<$from> for $to {
+            fn from(value: $from) -> Self {
+                let $x = value.0;
+                Self($forward)
+            }
+        }
+
+        impl From<$to> for $from {
+            fn from(value: $to) -> Self {
+                let $y = value.0;
+                Self($back)
+            }
+        }
+    };
+}
+
+const METRES_PER_MILE: f32 = 1609.344;
+const METRES_PER_NAUTICAL_MILE: f32 = 1852.0;
+const METRES_PER_FOOT: f32 = 0.3048;
+const MILLIMETRES_PER_INCH: f32 = 25.4;
+const PASCALS_PER_INCH_OF_MERCURY: f32 = 3386.389;
+const PASCALS_PER_MILLIMETRE_OF_MERCURY: f32 = 133.322_39;
+
+unit!(
+    /// Temperature in degrees Fahrenheit, displayed to one decimal place
+    Fahrenheit,
+    "°F",
+    1
+);
+unit!(
+    /// Thermodynamic temperature in kelvin, displayed to two decimal places
+    Kelvin,
+    " K",
+    2
+);
+convert!(Celsius => Fahrenheit, |c| c * 9.0 / 5.0 + 32.0, |f| (f - 32.0) * 5.0 / 9.0);
+convert!(Celsius => Kelvin, |c| c + 273.15, |k| k - 273.15);
+
+unit!(
+    /// Speed in statute miles per hour, displayed to one decimal place
+    MilesPerHour,
+    " mph",
+    1
+);
+unit!(
+    /// Speed in kilometres per hour, displayed to one decimal place
+    KilometresPerHour,
+    " km/h",
+    1
+);
+unit!(
+    /// Speed in knots (nautical miles per hour), displayed to one decimal place
+    Knots,
+    " kn",
+    1
+);
+convert!(MetresPerSecond => MilesPerHour, |s| s * 3600.0 / METRES_PER_MILE, |s| s * METRES_PER_MILE / 3600.0);
+convert!(MetresPerSecond => KilometresPerHour, |s| s * 3.6, |s| s / 3.6);
+convert!(MetresPerSecond => Knots, |s| s * 3600.0 / METRES_PER_NAUTICAL_MILE, |s| s * METRES_PER_NAUTICAL_MILE / 3600.0);
+
+unit!(
+    /// Pressure in hectopascals, displayed to one decimal place
+    Hectopascals,
+    " hPa",
+    1
+);
+unit!(
+    /// Pressure in millibars, displayed to one decimal place
+    ///
+    /// One millibar is equal to one hectopascal.
+    Millibars,
+    " mbar",
+    1
+);
+unit!(
+    /// Pressure in inches of mercury, displayed to two decimal places
+    InchesOfMercury,
+    " inHg",
+    2
+);
+unit!(
+    /// Pressure in millimetres of mercury, displayed to one decimal place
+    MillimetresOfMercury,
+    " mmHg",
+    1
+);
+
+/// Implement `From` in both directions between `Pascals` and a pressure unit newtype
+///
+/// Conversions to `Pascals` are rounded to the nearest whole Pascal.
+macro_rules! convert_pascals {
+    ($to:ident, $pascals_per_unit:expr) => {
+        impl From<Pascals> for $to {
+            fn from(value: Pascals) -> Self {
+                Self(value.0 as f32 / $pascals_per_unit)
+            }
+        }
+
+        impl From<$to> for Pascals {
+            fn from(value: $to) -> Self {
+                Self(libm::roundf(value.0 * $pascals_per_unit) as u32)
+            }
+        }
+    };
+}
+
+convert_pascals!(Hectopascals, 100.0);
+convert_pascals!(Millibars, 100.0);
+convert_pascals!(InchesOfMercury, PASCALS_PER_INCH_OF_MERCURY);
+convert_pascals!(MillimetresOfMercury, PASCALS_PER_MILLIMETRE_OF_MERCURY);
+
+unit!(
+    /// Length in inches, displayed to two decimal places
+    Inches,
+    " in",
+    2
+);
+unit!(
+    /// Length in feet, displayed to the nearest foot
+    Feet,
+    " ft",
+    0
+);
+unit!(
+    /// Distance in statute miles, displayed to one decimal place
+    Miles,
+    " mi",
+    1
+);
+unit!(
+    /// Distance in kilometres, displayed to one decimal place
+    Kilometres,
+    " km",
+    1
+);
+convert!(Millimetres => Inches, |mm| mm / MILLIMETRES_PER_INCH, |i| i * MILLIMETRES_PER_INCH);
+convert!(Metres => Feet, |m| m / METRES_PER_FOOT, |ft| ft * METRES_PER_FOOT);
+convert!(Metres => Miles, |m| m / METRES_PER_MILE, |mi| mi * METRES_PER_MILE);
+unit!(
+    /// Distance in nautical miles, displayed to one decimal place
+    NauticalMiles,
+    " nmi",
+    1
+);
+convert!(Metres => Kilometres, |m| m / 1000.0, |km| km * 1000.0);
+convert!(Metres => NauticalMiles, |m| m / METRES_PER_NAUTICAL_MILE, |nmi| nmi * METRES_PER_NAUTICAL_MILE);
+
+unit!(
+    /// Mass concentration, such as absolute humidity, displayed to one decimal place
+    GramsPerCubicMetre,
+    " g/m³",
+    1
+);
+unit!(
+    /// Mass ratio, such as the humidity mixing ratio, displayed to one decimal place
+    GramsPerKilogram,
+    " g/kg",
+    1
+);
+
+/// Temperature scale used when displaying temperatures
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    /// Convert a temperature to this scale
+    pub fn convert(&self, t: Celsius) -> f32 {
+        match self {
+            TemperatureUnit::Celsius => t.0,
+            TemperatureUnit::Fahrenheit => Fahrenheit::from(t).0,
+        }
+    }
+
+    /// Unit symbol, such as "°C"
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+        }
+    }
+
+    /// Format a temperature in this scale to one decimal place, with its symbol
+    pub fn format(&self, t: Celsius) -> String {
+        match self {
+            TemperatureUnit::Celsius => format!("{t:.1}"),
+            TemperatureUnit::Fahrenheit => format!("{:.1}", Fahrenheit::from(t)),
+        }
+    }
+}
+
+/// Unit used when displaying wind speeds
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SpeedUnit {
+    #[default]
+    MetresPerSecond,
+    MilesPerHour,
+    KilometresPerHour,
+    Knots,
+}
+
+impl SpeedUnit {
+    /// Convert a speed to this unit
+    pub fn convert(&self, s: MetresPerSecond) -> f32 {
+        match self {
+            SpeedUnit::MetresPerSecond => s.0,
+            SpeedUnit::MilesPerHour => MilesPerHour::from(s).0,
+            SpeedUnit::KilometresPerHour => KilometresPerHour::from(s).0,
+            SpeedUnit::Knots => Knots::from(s).0,
+        }
+    }
+
+    /// Unit symbol, such as "mph"
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SpeedUnit::MetresPerSecond => "m/s",
+            SpeedUnit::MilesPerHour => "mph",
+            SpeedUnit::KilometresPerHour => "km/h",
+            SpeedUnit::Knots => "kn",
+        }
+    }
+
+    /// Format a speed in this unit to one decimal place, with its symbol
+    pub fn format(&self, s: MetresPerSecond) -> String {
+        match self {
+            SpeedUnit::MetresPerSecond => format!("{s:.1}"),
+            SpeedUnit::MilesPerHour => format!("{:.1}", MilesPerHour::from(s)),
+            SpeedUnit::KilometresPerHour => format!("{:.1}", KilometresPerHour::from(s)),
+            SpeedUnit::Knots => format!("{:.1}", Knots::from(s)),
+        }
+    }
+}
+
+/// Unit used when displaying air pressure
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PressureUnit {
+    #[default]
+    Hectopascals,
+    InchesOfMercury,
+}
+
+impl PressureUnit {
+    /// Convert a pressure to this unit
+    pub fn convert(&self, p: Pascals) -> f32 {
+        match self {
+            PressureUnit::Hectopascals => Hectopascals::from(p).0,
+            PressureUnit::InchesOfMercury => InchesOfMercury::from(p).0,
+        }
+    }
+
+    /// Unit symbol, such as "hPa"
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PressureUnit::Hectopascals => "hPa",
+            PressureUnit::InchesOfMercury => "inHg",
+        }
+    }
+
+    /// Format a pressure in this unit to two decimal places, with its symbol
+    ///
+    /// Pressures are given by the Met Office in whole Pascals, which is two decimal places in
+    /// hectopascals.
+    pub fn format(&self, p: Pascals) -> String {
+        match self {
+            PressureUnit::Hectopascals => format!("{:.2}", Hectopascals::from(p)),
+            PressureUnit::InchesOfMercury => format!("{:.2}", InchesOfMercury::from(p)),
+        }
+    }
+}
+
+/// Unit used when displaying distances, such as visibility
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceUnit {
+    #[default]
+    Metres,
+    Kilometres,
+    Miles,
+    NauticalMiles,
+}
+
+impl DistanceUnit {
+    /// Convert a distance to this unit
+    pub fn convert(&self, d: Metres) -> f32 {
+        match self {
+            DistanceUnit::Metres => d.0,
+            DistanceUnit::Kilometres => Kilometres::from(d).0,
+            DistanceUnit::Miles => Miles::from(d).0,
+            DistanceUnit::NauticalMiles => NauticalMiles::from(d).0,
+        }
+    }
+
+    /// Unit symbol, such as "km"
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            DistanceUnit::Metres => "m",
+            DistanceUnit::Kilometres => "km",
+            DistanceUnit::Miles => "mi",
+            DistanceUnit::NauticalMiles => "nmi",
+        }
+    }
+
+    /// Format a distance in this unit to one decimal place, with its symbol
+    pub fn format(&self, d: Metres) -> String {
+        match self {
+            DistanceUnit::Metres => format!("{d:.1}"),
+            DistanceUnit::Kilometres => format!("{:.1}", Kilometres::from(d)),
+            DistanceUnit::Miles => format!("{:.1}", Miles::from(d)),
+            DistanceUnit::NauticalMiles => format!("{:.1}", NauticalMiles::from(d)),
+        }
+    }
+}
+
+/// Unit used when displaying precipitation amounts and rates
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PrecipitationUnit {
+    #[default]
+    Millimetres,
+    Inches,
+}
+
+impl PrecipitationUnit {
+    /// Convert a precipitation amount to this unit
+    pub fn convert(&self, p: Millimetres) -> f32 {
+        match self {
+            PrecipitationUnit::Millimetres => p.0,
+            PrecipitationUnit::Inches => Inches::from(p).0,
+        }
+    }
+
+    /// Unit symbol, such as "mm"
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PrecipitationUnit::Millimetres => "mm",
+            PrecipitationUnit::Inches => "in",
+        }
+    }
+
+    /// Format a precipitation amount in this unit to two decimal places, with its symbol
+    pub fn format(&self, p: Millimetres) -> String {
+        match self {
+            PrecipitationUnit::Millimetres => format!("{p:.2}"),
+            PrecipitationUnit::Inches => format!("{:.2}", Inches::from(p)),
+        }
+    }
+
+    /// Format a precipitation rate in this unit per hour to two decimal places, with its symbol
+    pub fn format_rate(&self, r: MillimetresPerHour) -> String {
+        match self {
+            PrecipitationUnit::Millimetres => format!("{r:.2}"),
+            PrecipitationUnit::Inches => format!("{:.2}/hour", Inches::from(Millimetres(r.0))),
+        }
+    }
+}
+
+/// A set of display units, chosen once and applied to every field of a prediction
+///
+/// Presets are provided for common conventions, and the default is the units used by the Met
+/// Office API itself (with pressure in hectopascals). Single values can be formatted with, for
+/// example, [`UnitSystem::format_temperature`], and whole predictions with, for example,
+/// [`Hourly::formatted`].
+///
+/// Each quantity is given to the same number of decimal places whatever its unit, so that every
+/// unit system shows values to a similar precision:
+///
+/// | Quantity | Decimal places |
+/// |----------|---------------:|
+/// | Temperature | 1 |
+/// | Speed | 1 |
+/// | Pressure | 2 |
+/// | Distance | 1 |
+/// | Precipitation amount and rate | 2 |
+///
+/// [`Hourly::formatted`]: crate::Hourly::formatted
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UnitSystem {
+    pub temperature: TemperatureUnit,
+    pub speed: SpeedUnit,
+    pub pressure: PressureUnit,
+    pub distance: DistanceUnit,
+    pub precipitation: PrecipitationUnit,
+}
+
+impl UnitSystem {
+    /// °C, mph, hPa, visibility in miles and precipitation in millimetres
+    pub const UK: Self = Self {
+        temperature: TemperatureUnit::Celsius,
+        speed: SpeedUnit::MilesPerHour,
+        pressure: PressureUnit::Hectopascals,
+        distance: DistanceUnit::Miles,
+        precipitation: PrecipitationUnit::Millimetres,
+    };
+
+    /// °F, mph, inHg, visibility in miles and precipitation in inches
+    pub const US: Self = Self {
+        temperature: TemperatureUnit::Fahrenheit,
+        speed: SpeedUnit::MilesPerHour,
+        pressure: PressureUnit::InchesOfMercury,
+        distance: DistanceUnit::Miles,
+        precipitation: PrecipitationUnit::Inches,
+    };
+
+    /// °C, km/h, hPa, visibility in kilometres and precipitation in millimetres
+    pub const METRIC: Self = Self {
+        temperature: TemperatureUnit::Celsius,
+        speed: SpeedUnit::KilometresPerHour,
+        pressure: PressureUnit::Hectopascals,
+        distance: DistanceUnit::Kilometres,
+        precipitation: PrecipitationUnit::Millimetres,
+    };
+
+    /// °C, knots, hPa, visibility in nautical miles and precipitation in millimetres
+    pub const MARINE: Self = Self {
+        temperature: TemperatureUnit::Celsius,
+        speed: SpeedUnit::Knots,
+        pressure: PressureUnit::Hectopascals,
+        distance: DistanceUnit::NauticalMiles,
+        precipitation: PrecipitationUnit::Millimetres,
+    };
+
+    /// Format a temperature, such as "63.8°F"
+    pub fn format_temperature(&self, t: Celsius) -> String {
+        self.temperature.format(t)
+    }
+
+    /// Format a speed, such as "10.8 mph"
+    pub fn format_speed(&self, s: MetresPerSecond) -> String {
+        self.speed.format(s)
+    }
+
+    /// Format a pressure, such as "29.94 inHg"
+    pub fn format_pressure(&self, p: Pascals) -> String {
+        self.pressure.format(p)
+    }
+
+    /// Format a distance, such as a [`Visibility`], for example "4.1 mi"
+    pub fn format_distance(&self, d: impl Into<Metres>) -> String {
+        self.distance.format(d.into())
+    }
+
+    /// Format a precipitation amount, such as "0.07 in"
+    pub fn format_precipitation(&self, p: Millimetres) -> String {
+        self.precipitation.format(p)
+    }
+
+    /// Format a precipitation rate, such as "1.41 mm/hour"
+    pub fn format_precipitation_rate(&self, r: MillimetresPerHour) -> String {
+        self.precipitation.format_rate(r)
+    }
+}
+
+/// Horizontal visibility in metres
+///
+/// This is the distance at which a known object can be seen horizontally from screen level
+/// (1.5m). Visibilities can be classified into the bands used in Met Office forecasts with
+/// [`Visibility::category`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Visibility(pub f32);
+
+impl Visibility {
+    /// Met Office visibility band
+    pub fn category(&self) -> VisibilityCategory {
+        VisibilityCategory::from(*self)
+    }
+
+    /// Whether the visibility is low enough to be described as fog (below 1km)
+    pub fn is_fog(&self) -> bool {
+        self.0 < 1000.0
+    }
+}
+
+impl From<Visibility> for Metres {
+    fn from(value: Visibility) -> Self {
+        Metres(value.0)
+    }
+}
+
+impl core::fmt::Display for Visibility {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Metres::from(*self).fmt(f)
+    }
+}
+
+/// Visibility bands used in Met Office forecasts
+///
+/// | Category | Visibility |
+/// |----------|------------|
+/// | Very poor | Less than 1km |
+/// | Poor | 1km to 4km |
+/// | Moderate | 4km to 10km |
+/// | Good | 10km to 20km |
+/// | Very good | 20km to 40km |
+/// | Excellent | More than 40km |
+///
+/// Boundaries belong to the higher band, so exactly 4km is moderate, apart from 40km, which is
+/// very good as excellent visibility is more than 40km.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VisibilityCategory {
+    VeryPoor,
+    Poor,
+    Moderate,
+    Good,
+    VeryGood,
+    Excellent,
+}
+
+impl VisibilityCategory {
+    /// Human-readable name of this category, such as "Very good"
+    pub fn label(&self) -> &'static str {
+        match self {
+            VisibilityCategory::VeryPoor => "Very poor",
+            VisibilityCategory::Poor => "Poor",
+            VisibilityCategory::Moderate => "Moderate",
+            VisibilityCategory::Good => "Good",
+            VisibilityCategory::VeryGood => "Very good",
+            VisibilityCategory::Excellent => "Excellent",
+        }
+    }
+
+    /// Range of visibilities in this category, such as "Between 4-10 km"
+    pub fn description(&self) -> &'static str {
+        match self {
+            VisibilityCategory::VeryPoor => "Less than 1 km",
+            VisibilityCategory::Poor => "Between 1-4 km",
+            VisibilityCategory::Moderate => "Between 4-10 km",
+            VisibilityCategory::Good => "Between 10-20 km",
+            VisibilityCategory::VeryGood => "Between 20-40 km",
+            VisibilityCategory::Excellent => "More than 40 km",
+        }
+    }
+}
+
+impl From<Visibility> for VisibilityCategory {
+    fn from(visibility: Visibility) -> Self {
+        match visibility.0 {
+            v if v < 1_000.0 => VisibilityCategory::VeryPoor,
+            v if v < 4_000.0 => VisibilityCategory::Poor,
+            v if v < 10_000.0 => VisibilityCategory::Moderate,
+            v if v < 20_000.0 => VisibilityCategory::Good,
+            v if v <= 40_000.0 => VisibilityCategory::VeryGood,
+            _ => VisibilityCategory::Excellent,
+        }
+    }
+}
+
+impl core::fmt::Display for VisibilityCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
 /// Degrees representing an azimuth
 ///
 /// This represents a direction, from the perspective of a weather forecast location, relative to
@@ -182,7 +768,8 @@ pub struct Degrees(pub f32);
 
 impl core::fmt::Display for Degrees {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:.0}°", self.0)
+        let p = f.precision().unwrap_or(0);
+        write!(f, "{:.*}°", p, self.0)
     }
 }
 
@@ -194,15 +781,100 @@ pub struct UvIndex(pub u8);
 
 impl UvIndex {
     /// Safety advice message for given UV index.
+    ///
+    /// This is the advice for the index's [`UvCategory`].
     pub fn advice_message(&self) -> &'static str {
-        match self.0 {
-            0..=2 => "No protection required. You can safely stay outside.",
-            3..=5 => "Seek shade during midday hours, cover up and wear sunscreen.",
-            6.. => {
+        self.category().advice()
+    }
+
+    /// WHO UV index band
+    pub fn category(&self) -> UvCategory {
+        UvCategory::from(*self)
+    }
+
+    /// Whether sun protection is needed, which the WHO recommends from an index of 3
+    pub fn protection_needed(&self) -> bool {
+        self.category() >= UvCategory::Moderate
+    }
+}
+
+/// UV index bands from the WHO Global Solar UV Index
+///
+/// | Category | UV index |
+/// |----------|---------:|
+/// | Low | 0–2 |
+/// | Moderate | 3–5 |
+/// | High | 6–7 |
+/// | Very high | 8–10 |
+/// | Extreme | 11+ |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UvCategory {
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+    Extreme,
+}
+
+impl UvCategory {
+    /// Human-readable name of this category, such as "Very high"
+    pub fn label(&self) -> &'static str {
+        match self {
+            UvCategory::Low => "Low",
+            UvCategory::Moderate => "Moderate",
+            UvCategory::High => "High",
+            UvCategory::VeryHigh => "Very high",
+            UvCategory::Extreme => "Extreme",
+        }
+    }
+
+    /// Met Office sun protection advice for this category
+    pub fn advice(&self) -> &'static str {
+        match self {
+            UvCategory::Low => "No protection required. You can safely stay outside.",
+            UvCategory::Moderate => {
+                "Take care during midday hours and do not spend too much time in the sun \
+                 unprotected."
+            }
+            UvCategory::High => "Seek shade during midday hours, cover up and wear sunscreen.",
+            UvCategory::VeryHigh => {
+                "Spend time in the shade between 11am and 3pm. \
+                 Shirt, sunscreen and hat are essential."
+            }
+            UvCategory::Extreme => {
                 "Avoid being outside during midday hours. Shirt, sunscreen and hat are essential."
             }
         }
     }
+
+    /// Standard WHO colour for this category, as a CSS hex colour
+    pub fn colour(&self) -> &'static str {
+        match self {
+            UvCategory::Low => "#289500",
+            UvCategory::Moderate => "#f7e400",
+            UvCategory::High => "#f85900",
+            UvCategory::VeryHigh => "#d8001d",
+            UvCategory::Extreme => "#6b49c8",
+        }
+    }
+}
+
+impl From<UvIndex> for UvCategory {
+    fn from(index: UvIndex) -> Self {
+        match index.0 {
+            0..=2 => UvCategory::Low,
+            3..=5 => UvCategory::Moderate,
+            6..=7 => UvCategory::High,
+            8..=10 => UvCategory::VeryHigh,
+            11.. => UvCategory::Extreme,
+        }
+    }
+}
+
+impl core::fmt::Display for UvCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.label())
+    }
 }
 
 impl core::fmt::Display for UvIndex {
@@ -293,10 +965,250 @@ impl TryFrom<i8> for Conditions {
     }
 }
 
-impl core::fmt::Display for Conditions {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl Conditions {
+    /// Met Office significant weather code for these conditions
+    ///
+    /// This is the inverse of `Conditions::try_from(code)`.
+    pub fn to_code(&self) -> i8 {
+        use Conditions::*;
+        match self {
+            TraceRain => -1,
+            ClearNight => 0,
+            SunnyDay => 1,
+            PartlyCloudyNight => 2,
+            PartlyCloudyDay => 3,
+            Mist => 5,
+            Fog => 6,
+            Cloudy => 7,
+            Overcast => 8,
+            LightRainShowerNight => 9,
+            LightRainShowerDay => 10,
+            Drizzle => 11,
+            LightRain => 12,
+            HeavyRainShowerNight => 13,
+            HeavyRainShowerDay => 14,
+            HeavyRain => 15,
+            SleetShowerNight => 16,
+            SleetShowerDay => 17,
+            Sleet => 18,
+            HailShowerNight => 19,
+            HailShowerDay => 20,
+            Hail => 21,
+            LightSnowShowerNight => 22,
+            LightSnowShowerDay => 23,
+            LightSnow => 24,
+            HeavySnowShowerNight => 25,
+            HeavySnowShowerDay => 26,
+            HeavySnow => 27,
+            ThunderShowerNight => 28,
+            ThunderShowerDay => 29,
+            Thunder => 30,
+        }
+    }
+}
+
+impl Conditions {
+    /// Whether these are the night-time variant of conditions with day and night variants
+    ///
+    /// Conditions without separate variants, such as `Cloudy`, are neither day nor night.
+    pub fn is_night(&self) -> bool {
+        use Conditions::*;
+        matches!(
+            self,
+            ClearNight
+                | PartlyCloudyNight
+                | LightRainShowerNight
+                | HeavyRainShowerNight
+                | SleetShowerNight
+                | HailShowerNight
+                | LightSnowShowerNight
+                | HeavySnowShowerNight
+                | ThunderShowerNight
+        )
+    }
+
+    /// Whether these are the daytime variant of conditions with day and night variants
+    pub fn is_day(&self) -> bool {
+        self.to_night() != *self
+    }
+
+    /// The daytime variant of these conditions, or the conditions themselves if there is none
+    ///
+    /// For example, `ClearNight` becomes `SunnyDay`.
+    pub fn to_day(&self) -> Conditions {
+        use Conditions::*;
+        match self {
+            ClearNight => SunnyDay,
+            PartlyCloudyNight => PartlyCloudyDay,
+            LightRainShowerNight => LightRainShowerDay,
+            HeavyRainShowerNight => HeavyRainShowerDay,
+            SleetShowerNight => SleetShowerDay,
+            HailShowerNight => HailShowerDay,
+            LightSnowShowerNight => LightSnowShowerDay,
+            HeavySnowShowerNight => HeavySnowShowerDay,
+            ThunderShowerNight => ThunderShowerDay,
+            other => *other,
+        }
+    }
+
+    /// The night-time variant of these conditions, or the conditions themselves if there is none
+    ///
+    /// For example, `SunnyDay` becomes `ClearNight`.
+    pub fn to_night(&self) -> Conditions {
+        use Conditions::*;
+        match self {
+            SunnyDay => ClearNight,
+            PartlyCloudyDay => PartlyCloudyNight,
+            LightRainShowerDay => LightRainShowerNight,
+            HeavyRainShowerDay => HeavyRainShowerNight,
+            SleetShowerDay => SleetShowerNight,
+            HailShowerDay => HailShowerNight,
+            LightSnowShowerDay => LightSnowShowerNight,
+            HeavySnowShowerDay => HeavySnowShowerNight,
+            ThunderShowerDay => ThunderShowerNight,
+            other => *other,
+        }
+    }
+
+    /// Kind of precipitation, if any
+    pub fn precipitation_kind(&self) -> PrecipitationKind {
+        use Conditions::*;
+        match self {
+            ClearNight | SunnyDay | PartlyCloudyNight | PartlyCloudyDay | Mist | Fog | Cloudy
+            | Overcast => PrecipitationKind::None,
+            Drizzle => PrecipitationKind::Drizzle,
+            TraceRain | LightRainShowerNight | LightRainShowerDay | LightRain
+            | HeavyRainShowerNight | HeavyRainShowerDay | HeavyRain => PrecipitationKind::Rain,
+            SleetShowerNight | SleetShowerDay | Sleet => PrecipitationKind::Sleet,
+            HailShowerNight | HailShowerDay | Hail => PrecipitationKind::Hail,
+            LightSnowShowerNight | LightSnowShowerDay | LightSnow | HeavySnowShowerNight
+            | HeavySnowShowerDay | HeavySnow => PrecipitationKind::Snow,
+            ThunderShowerNight | ThunderShowerDay | Thunder => PrecipitationKind::Thunder,
+        }
+    }
+
+    /// Intensity of precipitation
+    ///
+    /// Returns `None` when there is no precipitation, and for sleet, hail and thunder, for which
+    /// the Met Office does not give an intensity.
+    pub fn intensity(&self) -> Option<Intensity> {
+        use Conditions::*;
+        match self {
+            TraceRain => Some(Intensity::Trace),
+            Drizzle | LightRain | LightRainShowerNight | LightRainShowerDay | LightSnow
+            | LightSnowShowerNight | LightSnowShowerDay => Some(Intensity::Light),
+            HeavyRain | HeavyRainShowerNight | HeavyRainShowerDay | HeavySnow
+            | HeavySnowShowerNight | HeavySnowShowerDay => Some(Intensity::Heavy),
+            _ => None,
+        }
+    }
+
+    /// Whether the precipitation is showery rather than continuous
+    pub fn is_shower(&self) -> bool {
+        use Conditions::*;
+        matches!(
+            self,
+            LightRainShowerNight
+                | LightRainShowerDay
+                | HeavyRainShowerNight
+                | HeavyRainShowerDay
+                | SleetShowerNight
+                | SleetShowerDay
+                | HailShowerNight
+                | HailShowerDay
+                | LightSnowShowerNight
+                | LightSnowShowerDay
+                | HeavySnowShowerNight
+                | HeavySnowShowerDay
+                | ThunderShowerNight
+                | ThunderShowerDay
+        )
+    }
+
+    /// Rank of these conditions by their impact, from 0 (clear skies) to 21 (thunder)
+    ///
+    /// Day and night variants have the same severity. Use this to pick the most significant
+    /// conditions over a period, for example with `max_by_key(|c| c.severity())`. The order is:
+    ///
+    /// clear, partly cloudy, cloudy, overcast, mist, fog, trace rain, drizzle, light rain
+    /// showers, light rain, heavy rain showers, heavy rain, sleet showers, sleet, light snow
+    /// showers, light snow, hail showers, hail, heavy snow showers, heavy snow, thunder showers,
+    /// thunder.
+    pub fn severity(&self) -> u8 {
+        use Conditions::*;
+        match self {
+            ClearNight | SunnyDay => 0,
+            PartlyCloudyNight | PartlyCloudyDay => 1,
+            Cloudy => 2,
+            Overcast => 3,
+            Mist => 4,
+            Fog => 5,
+            TraceRain => 6,
+            Drizzle => 7,
+            LightRainShowerDay | LightRainShowerNight => 8,
+            LightRain => 9,
+            HeavyRainShowerDay | HeavyRainShowerNight => 10,
+            HeavyRain => 11,
+            SleetShowerDay | SleetShowerNight => 12,
+            Sleet => 13,
+            LightSnowShowerDay | LightSnowShowerNight => 14,
+            LightSnow => 15,
+            HailShowerDay | HailShowerNight => 16,
+            Hail => 17,
+            HeavySnowShowerDay | HeavySnowShowerNight => 18,
+            HeavySnow => 19,
+            ThunderShowerDay | ThunderShowerNight => 20,
+            Thunder => 21,
+        }
+    }
+
+    /// Emoji representing these conditions, such as "⛅"
+    pub fn emoji(&self) -> &'static str {
+        use Conditions::*;
+        match self {
+            ClearNight => "🌙",
+            SunnyDay => "☀️",
+            PartlyCloudyNight | Cloudy | Overcast => "☁️",
+            PartlyCloudyDay => "⛅",
+            Mist | Fog => "🌫️",
+            TraceRain => "💧",
+            LightRainShowerDay => "🌦️",
+            LightRainShowerNight | HeavyRainShowerNight | HeavyRainShowerDay | Drizzle
+            | LightRain | HeavyRain => "🌧️",
+            SleetShowerNight | SleetShowerDay | Sleet | HailShowerNight | HailShowerDay | Hail
+            | LightSnowShowerNight | LightSnowShowerDay | LightSnow => "🌨️",
+            HeavySnowShowerNight | HeavySnowShowerDay | HeavySnow => "❄️",
+            ThunderShowerNight | ThunderShowerDay => "⛈️",
+            Thunder => "🌩️",
+        }
+    }
+}
+
+/// Kind of precipitation described by [`Conditions`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrecipitationKind {
+    None,
+    Rain,
+    Drizzle,
+    Sleet,
+    Hail,
+    Snow,
+    Thunder,
+}
+
+/// Intensity of precipitation described by [`Conditions`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Intensity {
+    Trace,
+    Light,
+    Heavy,
+}
+
+impl Conditions {
+    /// English description, as used by `Display`
+    pub(crate) fn description(&self) -> &'static str {
         use Conditions::*;
-        let s: &'static str = match self {
+        match self {
             TraceRain => "Trace of rain",
             ClearNight => "Clear",
             SunnyDay => "Sunny",
@@ -320,8 +1232,13 @@ impl core::fmt::Display for Conditions {
             HeavySnow => "Heavy snow",
             ThunderShowerNight | ThunderShowerDay => "Thunder shower",
             Thunder => "Thunder",
-        };
-        write!(f, "{s}")
+        }
+    }
+}
+
+impl core::fmt::Display for Conditions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.description())
     }
 }
 
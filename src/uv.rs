@@ -0,0 +1,67 @@
+//! Sun protection windows from hourly UV index forecasts
+//!
+//! The WHO recommends sun protection whenever the UV index is 3 or above (see [`UvCategory`]).
+//! For an hourly forecast, the periods in which protection is needed can be found in local time:
+//!
+//! ```
+//! # use rjw_metoffice::{Forecast, Hourly};
+//! use jiff::tz::TimeZone;
+//! # let forecast: Forecast<Hourly> = include_str!("../tests/global-spot-hourly-sample.json").parse().unwrap();
+//! let london = TimeZone::posix("GMT0BST,M3.5.0/1,M10.5.0").unwrap();
+//! let window = &forecast.uv_protection_windows(&london)[1];
+//! assert_eq!(window.start.strftime("%H:%M").to_string(), "09:00");
+//! assert_eq!(window.end.strftime("%H:%M").to_string(), "18:00");
+//! ```
+//!
+//! [`UvCategory`]: crate::units::UvCategory
+use alloc::vec::Vec;
+
+use jiff::tz::TimeZone;
+use jiff::{SignedDuration, Zoned};
+
+use crate::units::UvIndex;
+use crate::{Forecast, Hourly};
+
+/// A continuous period in which sun protection is needed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtectionWindow {
+    /// Time of the first hour needing protection
+    pub start: Zoned,
+    /// End of the last hour needing protection (exclusive)
+    pub end: Zoned,
+    /// Highest UV index during the window
+    pub peak: UvIndex,
+}
+
+impl Forecast<Hourly> {
+    /// Periods in which the UV index is 3 or above, with times in the time zone `tz`
+    ///
+    /// Each hourly UV index is taken to last until the next prediction, so a window ends an hour
+    /// after its last hour needing protection. A window that is still open at the end of the
+    /// forecast ends an hour after the final prediction.
+    pub fn uv_protection_windows(&self, tz: &TimeZone) -> Vec<ProtectionWindow> {
+        let hour = SignedDuration::from_hours(1);
+        let mut windows: Vec<ProtectionWindow> = Vec::new();
+        let mut open = false;
+        for h in &self.predictions {
+            if !h.uv_index.protection_needed() {
+                open = false;
+                continue;
+            }
+            let end = h.time.timestamp() + hour;
+            match windows.last_mut() {
+                Some(window) if open => {
+                    window.end = end.to_zoned(tz.clone());
+                    window.peak = window.peak.max(h.uv_index);
+                }
+                _ => windows.push(ProtectionWindow {
+                    start: h.time.with_time_zone(tz.clone()),
+                    end: end.to_zoned(tz.clone()),
+                    peak: h.uv_index,
+                }),
+            }
+            open = true;
+        }
+        windows
+    }
+}
@@ -0,0 +1,198 @@
+use jiff::{SignedDuration, Zoned};
+use rjw_metoffice::alerts::{Alert, AlertLog, Comparison, Rule, Severity, rules_from_json};
+use rjw_metoffice::{Daily, Error, Forecast, Hourly};
+
+const HOURLY: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY: &str = include_str!("global-spot-daily-sample.json");
+
+fn window(alert: &Alert) -> String {
+    format!(
+        "{}–{}",
+        alert.start.strftime("%d %H:%M"),
+        alert.end.strftime("%d %H:%M")
+    )
+}
+
+fn gusts() -> Rule {
+    Rule {
+        name: "Gusts".into(),
+        field: "wind_gust_speed".into(),
+        comparison: Comparison::Above,
+        threshold: 10.0,
+        duration: SignedDuration::ZERO,
+        max_lead_time: None,
+        severity: Severity::Moderate,
+    }
+}
+
+#[test]
+pub fn rules_from_json_with_defaults() -> anyhow::Result<()> {
+    let rules = rules_from_json(
+        r#"[
+            {
+                "name": "Gusts",
+                "field": "wind_gust_speed",
+                "comparison": "above",
+                "threshold": 10,
+                "severity": "moderate"
+            },
+            {
+                "name": "Frost",
+                "field": "temperature",
+                "comparison": "at_most",
+                "threshold": 0,
+                "duration": "PT3H",
+                "max_lead_time": "36 hours",
+                "severity": "severe"
+            },
+            {
+                "name": "Heat",
+                "field": "temperature",
+                "comparison": "above",
+                "threshold": 30,
+                "max_lead_time": null,
+                "severity": "minor"
+            }
+        ]"#,
+    )?;
+    assert_eq!(rules[0], gusts());
+    assert_eq!(rules[1].comparison, Comparison::AtMost);
+    assert_eq!(rules[1].duration, SignedDuration::from_hours(3));
+    assert_eq!(rules[1].max_lead_time, Some(SignedDuration::from_hours(36)));
+    assert!(rules[1].severity > rules[0].severity);
+    assert_eq!(rules[2].max_lead_time, None);
+
+    let invalid = r#"[{"name": "x", "field": "temperature", "comparison": "above",
+        "threshold": 0, "duration": "soon", "severity": "minor"}]"#;
+    assert!(matches!(rules_from_json(invalid), Err(Error::Serde(_))));
+    Ok(())
+}
+
+#[test]
+pub fn runs_of_matching_predictions() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let alerts = forecast.alerts(&[gusts()])?;
+    let windows: Vec<String> = alerts.iter().map(window).collect();
+    assert_eq!(windows, ["05 14:00–05 18:00", "06 11:00–06 18:00"]);
+    assert_eq!(alerts[0].extreme, 11.12);
+    assert_eq!(alerts[1].extreme, 11.51);
+    assert!(alerts.iter().all(|a| a.rule == "Gusts"));
+    Ok(())
+}
+
+#[test]
+pub fn duration_and_lead_time() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let long = Rule {
+        duration: SignedDuration::from_hours(5),
+        ..gusts()
+    };
+    let windows: Vec<String> = forecast.alerts(&[long])?.iter().map(window).collect();
+    assert_eq!(windows, ["06 11:00–06 18:00"]);
+
+    // The model ran at 10:00 on the 5th.
+    let soon = Rule {
+        max_lead_time: Some(SignedDuration::from_hours(24)),
+        ..gusts()
+    };
+    let windows: Vec<String> = forecast.alerts(&[soon])?.iter().map(window).collect();
+    assert_eq!(windows, ["05 14:00–05 18:00"]);
+    Ok(())
+}
+
+#[test]
+pub fn below_threshold() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let cool = Rule {
+        name: "Cool".into(),
+        field: "temperature".into(),
+        comparison: Comparison::Below,
+        threshold: 12.0,
+        ..gusts()
+    };
+    let alerts = forecast.alerts(&[gusts(), cool])?;
+    let windows: Vec<String> = alerts.iter().map(window).collect();
+    // Ordered by start time across rules.
+    assert_eq!(
+        windows,
+        [
+            "05 14:00–05 18:00",
+            "06 02:00–06 05:00",
+            "06 11:00–06 18:00"
+        ]
+    );
+    assert_eq!(alerts[1].rule, "Cool");
+    assert_eq!(alerts[1].extreme, 11.57);
+    Ok(())
+}
+
+#[test]
+pub fn daily_fields() -> anyhow::Result<()> {
+    let forecast: Forecast<Daily> = DAILY.parse()?;
+    let cool_nights = Rule {
+        name: "Cool nights".into(),
+        field: "night_temperature_minimum".into(),
+        comparison: Comparison::AtMost,
+        threshold: 11.0,
+        ..gusts()
+    };
+    let alerts = forecast.alerts(&[cool_nights])?;
+    let dates: Vec<String> = alerts
+        .iter()
+        .map(|a| format!("{}–{}", a.start.date(), a.end.date()))
+        .collect();
+    assert_eq!(dates, ["2023-07-04–2023-07-06", "2023-07-11–2023-07-12"]);
+    assert_eq!(alerts[0].extreme, 10.6);
+    Ok(())
+}
+
+#[test]
+pub fn unknown_field() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let typo = Rule {
+        field: "wind_gusts".into(),
+        ..gusts()
+    };
+    assert!(matches!(
+        forecast.alerts(&[typo]),
+        Err(Error::UnknownField(field)) if field == "wind_gusts"
+    ));
+    // Daily fields are prefixed.
+    let daily: Forecast<Daily> = DAILY.parse()?;
+    assert!(matches!(
+        daily.alerts(&[gusts()]),
+        Err(Error::UnknownField(_))
+    ));
+    Ok(())
+}
+
+#[test]
+pub fn deduplicate_across_runs() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let mut log = AlertLog::new();
+    let first_run = forecast.alerts(&[gusts()])?;
+    assert_eq!(log.deduplicate(first_run.clone()), first_run);
+
+    // A later run with the same gusts an hour later, and a new alert from another rule.
+    let later = |alert: &Alert| Alert {
+        start: &alert.start + SignedDuration::from_hours(1),
+        end: &alert.end + SignedDuration::from_hours(1),
+        ..alert.clone()
+    };
+    let mut second_run: Vec<Alert> = first_run.iter().map(later).collect();
+    let frost = Alert {
+        rule: "Frost".into(),
+        severity: Severity::Severe,
+        ..first_run[0].clone()
+    };
+    second_run.push(frost.clone());
+    assert_eq!(log.deduplicate(second_run), [frost]);
+    assert_eq!(window(&log.issued()[0]), "05 15:00–05 19:00");
+
+    // Once forgotten, an alert is issued again.
+    let end: Zoned = "2023-07-05T19:00Z[UTC]".parse()?;
+    log.forget_before(&end);
+    assert_eq!(log.issued().len(), 1);
+    assert_eq!(log.deduplicate(first_run.clone()), first_run[..1]);
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use rjw_metoffice::beaufort::Beaufort;
+use rjw_metoffice::units::MetresPerSecond;
+use rjw_metoffice::{Daily, Forecast, Hourly};
+
+const HOURLY_SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY_SAMPLE: &str = include_str!("global-spot-daily-sample.json");
+
+/// Lower bound of each force from 1 upwards, in metres per second
+const THRESHOLDS: [f32; 12] = [
+    0.25, 1.55, 3.35, 5.45, 7.95, 10.75, 13.85, 17.15, 20.75, 24.45, 28.45, 32.65,
+];
+
+#[test]
+pub fn boundaries_at_every_threshold() {
+    for (i, threshold) in THRESHOLDS.iter().enumerate() {
+        let force = i as u8 + 1;
+        let below = Beaufort::from(MetresPerSecond(threshold - 0.01));
+        let at = Beaufort::from(MetresPerSecond(*threshold));
+        assert_eq!(below.force(), force - 1, "{threshold} m/s");
+        assert_eq!(at.force(), force, "{threshold} m/s");
+        assert_eq!(at.lower_bound(), MetresPerSecond(*threshold));
+    }
+}
+
+#[test]
+pub fn between_rounded_ranges() {
+    // WMO force 1 is 0.3–1.5 m/s and force 2 is 1.6–3.3 m/s.
+    assert_eq!(Beaufort::from(MetresPerSecond(1.5)), Beaufort::LightAir);
+    assert_eq!(Beaufort::from(MetresPerSecond(1.54)), Beaufort::LightAir);
+    assert_eq!(Beaufort::from(MetresPerSecond(1.55)), Beaufort::LightBreeze);
+    assert_eq!(Beaufort::from(MetresPerSecond(1.6)), Beaufort::LightBreeze);
+    assert_eq!(Beaufort::from(MetresPerSecond(0.25)), Beaufort::LightAir);
+}
+
+#[test]
+pub fn extremes() {
+    assert_eq!(Beaufort::from(MetresPerSecond(0.0)), Beaufort::Calm);
+    assert_eq!(Beaufort::from(MetresPerSecond(-1.0)), Beaufort::Calm);
+    assert_eq!(Beaufort::from(MetresPerSecond(80.0)), Beaufort::Hurricane);
+    assert_eq!(Beaufort::Hurricane.force(), 12);
+}
+
+#[test]
+pub fn names_and_descriptions() {
+    assert_eq!(Beaufort::FreshBreeze.name(), "Fresh breeze");
+    assert_eq!(Beaufort::Gale.to_string(), "Gale");
+    assert_eq!(Beaufort::Calm.sea_description(), "Sea like a mirror");
+    assert!(
+        Beaufort::Calm
+            .land_description()
+            .contains("smoke rises vertically")
+    );
+    assert!(Beaufort::NearGale < Beaufort::Gale);
+}
+
+#[test]
+pub fn prediction_helpers() -> anyhow::Result<()> {
+    let hourly: Forecast<Hourly> = HOURLY_SAMPLE.parse()?;
+    for h in &hourly.predictions {
+        assert_eq!(h.wind_force(), Beaufort::from(h.wind_speed));
+        assert_eq!(h.gust_force(), Beaufort::from(h.wind_gust_speed));
+        assert!(h.gust_force() >= h.wind_force());
+    }
+
+    let daily: Forecast<Daily> = DAILY_SAMPLE.parse()?;
+    for d in &daily.predictions {
+        assert!(d.day.gust_force() >= d.day.wind_force());
+        assert_eq!(d.night.wind_force(), Beaufort::from(d.night.wind_speed));
+    }
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use jiff::SignedDuration;
+use jiff::civil::date;
+use jiff::tz::TimeZone;
+use rjw_metoffice::{Daily, Forecast, Hourly};
+
+const HOURLY: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY: &str = include_str!("global-spot-daily-sample.json");
+
+fn london() -> TimeZone {
+    TimeZone::posix("GMT0BST,M3.5.0/1,M10.5.0").unwrap()
+}
+
+/// The hourly sample moved to the given three consecutive dates
+fn hourly_on(dates: [&str; 3]) -> anyhow::Result<Forecast<Hourly>> {
+    let json = HOURLY
+        .replace("2023-07-05", dates[0])
+        .replace("2023-07-06", dates[1])
+        .replace("2023-07-07", dates[2]);
+    Ok(json.parse()?)
+}
+
+#[test]
+pub fn local_days() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let days: Vec<_> = forecast.local_days(&london()).collect();
+    let dates: Vec<String> = days.iter().map(|d| d.date.to_string()).collect();
+    assert_eq!(dates, ["2023-07-05", "2023-07-06", "2023-07-07"]);
+    let counts: Vec<usize> = days.iter().map(|d| d.predictions.len()).collect();
+    // 10:00 UTC is 11:00 BST, and the last prediction is 11:00 BST on the 7th.
+    assert_eq!(counts, [13, 24, 12]);
+    assert_eq!(
+        days[1].start.strftime("%F %T %:z").to_string(),
+        "2023-07-06 00:00:00 +01:00"
+    );
+    assert!(
+        days.iter()
+            .all(|d| d.length() == SignedDuration::from_hours(24))
+    );
+
+    let utc: Vec<usize> = forecast
+        .local_days(&TimeZone::UTC)
+        .map(|d| d.predictions.len())
+        .collect();
+    assert_eq!(utc, [14, 24, 11]);
+    Ok(())
+}
+
+#[test]
+pub fn clocks_go_back() -> anyhow::Result<()> {
+    let forecast = hourly_on(["2023-10-28", "2023-10-29", "2023-10-30"])?;
+    let day = forecast
+        .local_days(&london())
+        .find(|d| d.date == date(2023, 10, 29))
+        .unwrap();
+    assert_eq!(day.length(), SignedDuration::from_hours(25));
+    assert_eq!(day.predictions.len(), 25);
+    assert_eq!(forecast.on_date(&london(), date(2023, 10, 29)).len(), 25);
+    Ok(())
+}
+
+#[test]
+pub fn clocks_go_forward() -> anyhow::Result<()> {
+    let forecast = hourly_on(["2023-03-25", "2023-03-26", "2023-03-27"])?;
+    let summaries = forecast.day_summaries(&london());
+    let day = summaries
+        .iter()
+        .find(|d| d.date == date(2023, 3, 26))
+        .unwrap();
+    assert_eq!(day.length, SignedDuration::from_hours(23));
+    assert_eq!(day.predictions, 23);
+    Ok(())
+}
+
+#[test]
+pub fn today_and_tomorrow() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let today = forecast.today(&london());
+    assert_eq!(today.len(), 13);
+    assert_eq!(today[0].time, forecast.predictions[0].time);
+    let tomorrow = forecast.tomorrow(&london());
+    assert_eq!(tomorrow.len(), 24);
+    // The model ran on a Wednesday, and the hourly forecast ends on Friday.
+    assert!(forecast.weekend(&london()).is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn weekend() -> anyhow::Result<()> {
+    let daily: Forecast<Daily> = DAILY.parse()?;
+    let dates: Vec<String> = daily
+        .weekend(&london())
+        .iter()
+        .map(|d| d.time.date().to_string())
+        .collect();
+    assert_eq!(dates, ["2023-07-08", "2023-07-09"]);
+
+    // 28 October 2023 was a Saturday, and the hourly forecast covers the rest of the weekend.
+    let forecast = hourly_on(["2023-10-28", "2023-10-29", "2023-10-30"])?;
+    let weekend = forecast.weekend(&london());
+    assert_eq!(weekend.len(), 13 + 25);
+    let range = forecast.on_dates(&london(), date(2023, 10, 28)..=date(2023, 10, 29));
+    assert_eq!(weekend.as_ptr_range(), range.as_ptr_range());
+    Ok(())
+}
+
+#[test]
+pub fn daily_dates_ignore_time_zone() -> anyhow::Result<()> {
+    let daily: Forecast<Daily> = DAILY.parse()?;
+    let new_york = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0")?;
+    let dates: Vec<String> = daily
+        .local_days(&new_york)
+        .map(|d| d.date.to_string())
+        .collect();
+    assert_eq!(dates.first().map(String::as_str), Some("2023-07-04"));
+    assert_eq!(dates.len(), 8);
+    let today: Vec<String> = daily
+        .today(&new_york)
+        .iter()
+        .map(|d| d.time.date().to_string())
+        .collect();
+    // The model ran at 10:00 UTC, 06:00 in New York.
+    assert_eq!(today, ["2023-07-05"]);
+    Ok(())
+}
+
+#[test]
+pub fn day_summaries() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let summaries = forecast.day_summaries(&london());
+    assert_eq!(summaries.len(), 3);
+    let today = &summaries[0];
+    let hours = forecast.today(&london());
+    let highest = hours
+        .iter()
+        .map(|h| {
+            h.temperature_maximum
+                .map_or(h.temperature.0, |t| t.0.max(h.temperature.0))
+        })
+        .fold(f32::MIN, f32::max);
+    assert_eq!(today.temperature_maximum.0, highest);
+    let worst = hours.iter().map(|h| h.conditions.severity()).max().unwrap();
+    assert_eq!(today.conditions.severity(), worst);
+    assert!(today.temperature_minimum.0 <= today.temperature_maximum.0);
+    Ok(())
+}
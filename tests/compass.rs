@@ -0,0 +1,99 @@
+use rjw_metoffice::compass::{CompassPoints, WindVector, mean_direction};
+use rjw_metoffice::units::{Degrees, MetresPerSecond};
+use rjw_metoffice::{Forecast, Hourly};
+
+const SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+#[test]
+pub fn compass_names() {
+    use CompassPoints::*;
+    let cases = [
+        (0.0, "N", "N", "N"),
+        (359.0, "N", "N", "N"),
+        (-10.0, "N", "N", "NbW"),
+        (22.5, "NE", "NNE", "NNE"),
+        (45.0, "NE", "NE", "NE"),
+        (90.0, "E", "E", "E"),
+        (101.25, "E", "ESE", "EbS"),
+        (180.0, "S", "S", "S"),
+        (282.0, "W", "WNW", "WbN"),
+        (292.5, "NW", "WNW", "WNW"),
+        (720.0, "N", "N", "N"),
+    ];
+    for (degrees, eight, sixteen, thirty_two) in cases {
+        let d = Degrees(degrees);
+        assert_eq!(d.compass(Eight), eight, "{degrees}");
+        assert_eq!(d.compass(Sixteen), sixteen, "{degrees}");
+        assert_eq!(d.compass(ThirtyTwo), thirty_two, "{degrees}");
+    }
+}
+
+#[test]
+pub fn normalised() {
+    let cases = [
+        (0.0, 0.0),
+        (359.5, 359.5),
+        (360.0, 0.0),
+        (450.0, 90.0),
+        (720.0, 0.0),
+        (-90.0, 270.0),
+        (-360.0, 0.0),
+        (-450.0, 270.0),
+        (-1e-6, 0.0),
+    ];
+    for (degrees, expected) in cases {
+        let n = Degrees(degrees).normalised().0;
+        assert!((0.0..360.0).contains(&n), "{degrees} -> {n}");
+        assert!(close(n, expected), "{degrees} -> {n}");
+    }
+}
+
+#[test]
+pub fn arrows_point_downwind() {
+    assert_eq!(Degrees(0.0).arrow(), '↓');
+    assert_eq!(Degrees(90.0).arrow(), '←');
+    assert_eq!(Degrees(180.0).arrow(), '↑');
+    assert_eq!(Degrees(225.0).arrow(), '↗');
+    assert_eq!(Degrees(270.0).arrow(), '→');
+}
+
+#[test]
+pub fn wind_components_round_trip() {
+    let southerly = WindVector::new(MetresPerSecond(5.0), Degrees(180.0));
+    assert!(close(southerly.u.0, 0.0));
+    assert!(close(southerly.v.0, 5.0));
+
+    for degrees in (0..360).step_by(15) {
+        let wind = WindVector::new(MetresPerSecond(7.5), Degrees(degrees as f32));
+        assert!(close(wind.speed().0, 7.5));
+        let back = wind.direction().0;
+        assert!(
+            close(back, degrees as f32) || close(back, 360.0),
+            "{degrees}"
+        );
+    }
+}
+
+#[test]
+pub fn circular_mean() {
+    let mean = mean_direction([Degrees(350.0), Degrees(10.0)]).unwrap();
+    assert!(close(mean.0, 0.0) || close(mean.0, 360.0), "{mean:?}");
+
+    let mean = mean_direction([Degrees(80.0), Degrees(90.0), Degrees(100.0)]).unwrap();
+    assert!(close(mean.0, 90.0));
+
+    assert_eq!(mean_direction([Degrees(0.0), Degrees(180.0)]), None);
+    assert_eq!(mean_direction([]), None);
+}
+
+#[test]
+pub fn forecast_mean_wind_direction() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    let mean = forecast.mean_wind_direction().unwrap();
+    assert!((0.0..360.0).contains(&mean.0));
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use rjw_metoffice::units::{Conditions, Intensity, PrecipitationKind};
+
+/// (code, is night, is day, precipitation kind, intensity, is shower)
+type Row = (i8, bool, bool, PrecipitationKind, Option<Intensity>, bool);
+
+/// Expected classification of every Met Office significant weather code
+const TABLE: &[Row] = {
+    use Intensity::*;
+    use PrecipitationKind as P;
+    &[
+        (-1, false, false, P::Rain, Some(Trace), false),
+        (0, true, false, P::None, None, false),
+        (1, false, true, P::None, None, false),
+        (2, true, false, P::None, None, false),
+        (3, false, true, P::None, None, false),
+        (5, false, false, P::None, None, false),
+        (6, false, false, P::None, None, false),
+        (7, false, false, P::None, None, false),
+        (8, false, false, P::None, None, false),
+        (9, true, false, P::Rain, Some(Light), true),
+        (10, false, true, P::Rain, Some(Light), true),
+        (11, false, false, P::Drizzle, Some(Light), false),
+        (12, false, false, P::Rain, Some(Light), false),
+        (13, true, false, P::Rain, Some(Heavy), true),
+        (14, false, true, P::Rain, Some(Heavy), true),
+        (15, false, false, P::Rain, Some(Heavy), false),
+        (16, true, false, P::Sleet, None, true),
+        (17, false, true, P::Sleet, None, true),
+        (18, false, false, P::Sleet, None, false),
+        (19, true, false, P::Hail, None, true),
+        (20, false, true, P::Hail, None, true),
+        (21, false, false, P::Hail, None, false),
+        (22, true, false, P::Snow, Some(Light), true),
+        (23, false, true, P::Snow, Some(Light), true),
+        (24, false, false, P::Snow, Some(Light), false),
+        (25, true, false, P::Snow, Some(Heavy), true),
+        (26, false, true, P::Snow, Some(Heavy), true),
+        (27, false, false, P::Snow, Some(Heavy), false),
+        (28, true, false, P::Thunder, None, true),
+        (29, false, true, P::Thunder, None, true),
+        (30, false, false, P::Thunder, None, false),
+    ]
+};
+
+#[test]
+pub fn every_code_is_classified() {
+    for code in -1..=30 {
+        let Some(&(_, night, day, kind, intensity, shower)) =
+            TABLE.iter().find(|row| row.0 == code)
+        else {
+            assert!(Conditions::try_from(code).is_err(), "{code}");
+            continue;
+        };
+        let c = Conditions::try_from(code).unwrap();
+        assert_eq!(c.to_code(), code, "{c:?}");
+        assert_eq!(c.is_night(), night, "{c:?}");
+        assert_eq!(c.is_day(), day, "{c:?}");
+        assert_eq!(c.precipitation_kind(), kind, "{c:?}");
+        assert_eq!(c.intensity(), intensity, "{c:?}");
+        assert_eq!(c.is_shower(), shower, "{c:?}");
+        assert!(!c.emoji().is_empty(), "{c:?}");
+
+        // Day and night variants are paired and equally severe.
+        assert!(!c.to_day().is_night(), "{c:?}");
+        assert!(!c.to_night().is_day(), "{c:?}");
+        assert_eq!(c.to_day().to_night(), c.to_night(), "{c:?}");
+        assert_eq!(c.to_night().severity(), c.to_day().severity(), "{c:?}");
+        if night || day {
+            assert_ne!(c.to_day(), c.to_night(), "{c:?}");
+        } else {
+            assert_eq!(c.to_day(), c, "{c:?}");
+            assert_eq!(c.to_night(), c, "{c:?}");
+        }
+    }
+}
+
+#[test]
+pub fn day_night_conversions() {
+    assert_eq!(Conditions::SunnyDay.to_night(), Conditions::ClearNight);
+    assert_eq!(Conditions::ClearNight.to_day(), Conditions::SunnyDay);
+    assert_eq!(
+        Conditions::HailShowerNight.to_day(),
+        Conditions::HailShowerDay
+    );
+}
+
+#[test]
+pub fn severity_ordering() {
+    use Conditions::*;
+    let ordered = [
+        SunnyDay,
+        PartlyCloudyDay,
+        Cloudy,
+        Overcast,
+        Mist,
+        Fog,
+        TraceRain,
+        Drizzle,
+        LightRainShowerDay,
+        LightRain,
+        HeavyRainShowerDay,
+        HeavyRain,
+        SleetShowerDay,
+        Sleet,
+        LightSnowShowerDay,
+        LightSnow,
+        HailShowerDay,
+        Hail,
+        HeavySnowShowerDay,
+        HeavySnow,
+        ThunderShowerDay,
+        Thunder,
+    ];
+    for pair in ordered.windows(2) {
+        assert!(pair[0].severity() < pair[1].severity(), "{pair:?}");
+    }
+    let worst = [Cloudy, HeavyRainShowerNight, Drizzle]
+        .into_iter()
+        .max_by_key(|c| c.severity());
+    assert_eq!(worst, Some(HeavyRainShowerNight));
+}
@@ -0,0 +1,112 @@
+use rjw_metoffice::units::UnitSystem;
+use rjw_metoffice::{Daily, Forecast, Hourly};
+
+const HOURLY_SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY_SAMPLE: &str = include_str!("global-spot-daily-sample.json");
+
+/// Formatted value of the named field
+fn field(text: &str, name: &str) -> Option<String> {
+    text.lines()
+        .find_map(|l| l.strip_prefix(name)?.strip_prefix(": "))
+        .map(String::from)
+}
+
+#[test]
+pub fn presets_format_hourly_values() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY_SAMPLE.parse()?;
+    let hour = &forecast.predictions[0];
+
+    let uk = UnitSystem::UK;
+    assert_eq!(uk.format_temperature(hour.temperature), "17.6°C");
+    assert!(uk.format_speed(hour.wind_speed).ends_with(" mph"));
+    assert!(uk.format_pressure(hour.pressure).ends_with(" hPa"));
+    assert!(uk.format_distance(hour.visibility).ends_with(" mi"));
+
+    let metric = UnitSystem::METRIC;
+    assert!(metric.format_speed(hour.wind_speed).ends_with(" km/h"));
+    assert!(metric.format_distance(hour.visibility).ends_with(" km"));
+
+    let us = UnitSystem::US;
+    assert_eq!(us.format_temperature(hour.temperature), "63.8°F");
+    assert!(
+        us.format_precipitation_rate(hour.precipitation_rate)
+            .ends_with(" in/hour")
+    );
+
+    let marine = UnitSystem::MARINE;
+    assert!(marine.format_speed(hour.wind_gust_speed).ends_with(" kn"));
+    assert!(marine.format_distance(hour.visibility).ends_with(" nmi"));
+    Ok(())
+}
+
+#[test]
+pub fn presets_share_precision() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY_SAMPLE.parse()?;
+    let hour = &forecast.predictions[0];
+    let decimals = |s: String| {
+        let number = s.split(|c: char| !(c.is_ascii_digit() || c == '.')).next();
+        number.and_then(|n| n.split_once('.')).map(|(_, d)| d.len())
+    };
+    for units in [
+        UnitSystem::default(),
+        UnitSystem::UK,
+        UnitSystem::US,
+        UnitSystem::METRIC,
+        UnitSystem::MARINE,
+    ] {
+        let t = units.format_temperature(hour.temperature);
+        assert_eq!(decimals(t.clone()), Some(1), "{t}");
+        let s = units.format_speed(hour.wind_speed);
+        assert_eq!(decimals(s.clone()), Some(1), "{s}");
+        let p = units.format_pressure(hour.pressure);
+        assert_eq!(decimals(p.clone()), Some(2), "{p}");
+        let d = units.format_distance(hour.visibility);
+        assert_eq!(decimals(d.clone()), Some(1), "{d}");
+        let r = units.format_precipitation_rate(hour.precipitation_rate);
+        assert_eq!(decimals(r.clone()), Some(2), "{r}");
+    }
+    Ok(())
+}
+
+#[test]
+pub fn formatted_hourly_fields() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY_SAMPLE.parse()?;
+    let hour = &forecast.predictions[0];
+    let uk = hour.formatted(&UnitSystem::UK).to_string();
+    assert_eq!(field(&uk, "temperature").as_deref(), Some("17.6°C"));
+    assert_eq!(field(&uk, "conditions").as_deref(), Some("Heavy rain"));
+    assert_eq!(field(&uk, "uv_index").as_deref(), Some("5"));
+    let pressure = UnitSystem::UK.format_pressure(hour.pressure);
+    assert_eq!(field(&uk, "pressure"), Some(pressure));
+
+    let us = hour.formatted(&UnitSystem::US);
+    let dew_point = us.iter().find(|(name, _)| *name == "dew_point_temperature");
+    assert!(dew_point.is_some_and(|(_, value)| value.ends_with("°F")));
+    Ok(())
+}
+
+#[test]
+pub fn missing_fields_are_omitted() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY_SAMPLE.parse()?;
+    let last = forecast.predictions.last().unwrap();
+    let formatted = last.formatted(&UnitSystem::UK);
+    assert!(
+        formatted
+            .iter()
+            .all(|(name, _)| name != "temperature_maximum")
+    );
+    assert!(!formatted.to_string().contains("temperature_maximum"));
+    Ok(())
+}
+
+#[test]
+pub fn daily_fields_are_prefixed() -> anyhow::Result<()> {
+    let forecast: Forecast<Daily> = DAILY_SAMPLE.parse()?;
+    let text = forecast.predictions[1]
+        .formatted(&UnitSystem::US)
+        .to_string();
+    assert!(text.contains("\nday_temperature_maximum: "));
+    assert!(text.contains("°F\n"));
+    assert!(field(&text, "night_pressure").is_some_and(|p| p.ends_with(" inHg")));
+    Ok(())
+}
@@ -0,0 +1,360 @@
+use rjw_metoffice::Error;
+use rjw_metoffice::geo::{CoordinateFormat, Geohash, GridReference, Maidenhead, Notation};
+use rjw_metoffice::units::{Coordinates, Latitude, Longitude};
+
+fn close(a: f64, b: f64, tolerance: f64) -> bool {
+    (a - b).abs() < tolerance
+}
+
+/// Worked example from the Ordnance Survey's "A Guide to Coordinate Systems in Great Britain"
+#[test]
+pub fn grid_reference_from_wgs84() -> anyhow::Result<()> {
+    let grid_ref = GridReference::from_lat_lon(Latitude::new(52.65798)?, Longitude::new(1.71605)?)?;
+    assert_eq!(grid_ref.to_string(), "TG 51409 13177");
+    assert!(close(grid_ref.easting(), 651_409.9, 5.0));
+    assert!(close(grid_ref.northing(), 313_177.3, 5.0));
+    Ok(())
+}
+
+#[test]
+pub fn grid_reference_to_wgs84() -> anyhow::Result<()> {
+    let grid_ref = GridReference::new(651_409.903, 313_177.270)?;
+    let (latitude, longitude) = grid_ref.to_lat_lon();
+    assert_eq!(format!("{:.2}", latitude.dms()), "52°39'28.72\"N");
+    assert_eq!(format!("{:.2}", longitude.dms()), "1°42'57.79\"E");
+    Ok(())
+}
+
+#[test]
+pub fn grid_reference_round_trip() -> anyhow::Result<()> {
+    // Exeter, Lerwick, Land's End, Dover
+    for (lat, lon) in [
+        (50.7256, -3.5269),
+        (60.1545, -1.1494),
+        (50.0686, -5.7161),
+        (51.1279, 1.3134),
+    ] {
+        let grid_ref = GridReference::from_lat_lon(Latitude::new(lat)?, Longitude::new(lon)?)?;
+        let (latitude, longitude) = grid_ref.to_lat_lon();
+        assert!(close(latitude.as_float(), lat, 1e-6), "{grid_ref}");
+        assert!(close(longitude.as_float(), lon, 1e-6), "{grid_ref}");
+    }
+    Ok(())
+}
+
+#[test]
+pub fn grid_reference_parsing() -> anyhow::Result<()> {
+    let cases = [
+        ("SV 00000 00000", 0.0, 0.0),
+        ("SX 925 925", 292_500.0, 92_500.0),
+        ("sx925925", 292_500.0, 92_500.0),
+        ("SX 92512 92538", 292_512.0, 92_538.0),
+        ("TG 51409 13177", 651_409.0, 313_177.0),
+        ("HU 4 4", 440_000.0, 1_140_000.0),
+        ("NN", 200_000.0, 700_000.0),
+    ];
+    for (s, easting, northing) in cases {
+        let grid_ref: GridReference = s.parse()?;
+        assert_eq!(
+            (grid_ref.easting(), grid_ref.northing()),
+            (easting, northing),
+            "{s}"
+        );
+    }
+    for s in [
+        "",
+        "S",
+        "SI 123 456",
+        "SX 12 345",
+        "SX 123456 123456",
+        "SX 12a 345",
+        "ZZ 1 1",
+    ] {
+        assert!(
+            matches!(s.parse::<GridReference>(), Err(Error::InvalidGridReference)),
+            "{s}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+pub fn grid_reference_display() -> anyhow::Result<()> {
+    let grid_ref = GridReference::new(292_599.9, 92_599.9)?;
+    assert_eq!(grid_ref.to_string(), "SX 92599 92599");
+    assert_eq!(format!("{grid_ref:.3}"), "SX 925 925");
+    assert_eq!(format!("{grid_ref:.1}"), "SX 9 9");
+    assert_eq!(format!("{grid_ref:.0}"), "SX");
+    assert_eq!(GridReference::new(0.0, 0.0)?.to_string(), "SV 00000 00000");
+    assert_eq!(
+        GridReference::new(440_000.0, 1_140_000.0)?.to_string(),
+        "HU 40000 40000"
+    );
+    assert!(GridReference::new(-1.0, 0.0).is_err());
+    assert!(GridReference::new(0.0, 1_300_000.0).is_err());
+    Ok(())
+}
+
+#[test]
+pub fn outside_national_grid() -> anyhow::Result<()> {
+    // New York
+    let result = GridReference::from_lat_lon(Latitude::new(40.7)?, Longitude::new(-74.0)?);
+    assert!(matches!(result, Err(Error::InvalidGridReference)));
+    Ok(())
+}
+
+#[test]
+pub fn parse_degrees() -> anyhow::Result<()> {
+    let latitudes = [
+        ("50.72", 50.72),
+        ("-12.5", -12.5),
+        ("50°43'12\"N", 50.72),
+        ("50° 43′ 12″ S", -50.72),
+        ("N 50 43.2", 50.72),
+        ("50:43:12 n", 50.72),
+        ("50°N", 50.0),
+        ("-50 43 12", -50.72),
+    ];
+    for (s, expected) in latitudes {
+        let latitude: Latitude = s.parse()?;
+        assert!(close(latitude.as_float(), expected, 1e-9), "{s}");
+    }
+
+    let longitudes = [
+        ("3°31'48\"W", -3.53),
+        ("3 31 48 E", 3.53),
+        ("W3.53", -3.53),
+        ("179°59'59.9\"E", 179.0 + 59.0 / 60.0 + 59.9 / 3600.0),
+    ];
+    for (s, expected) in longitudes {
+        let longitude: Longitude = s.parse()?;
+        assert!(close(longitude.as_float(), expected, 1e-9), "{s}");
+    }
+    Ok(())
+}
+
+#[test]
+pub fn unparseable_degrees() {
+    for s in [
+        "",
+        "N",
+        "abc",
+        "50°43'12\"E",
+        "-50°43'N",
+        "50°60'N",
+        "50°43'60\"N",
+        "50.5°30'N",
+        "50°30.5'15\"N",
+        "1 2 3 4",
+    ] {
+        assert!(
+            matches!(s.parse::<Latitude>(), Err(Error::UnparseableDegrees)),
+            "{s}"
+        );
+    }
+    assert!(matches!(
+        "91°N".parse::<Latitude>(),
+        Err(Error::GeographicDegreesOutOfBounds)
+    ));
+    assert!("3°W".parse::<Longitude>().is_ok());
+    assert!("3°N".parse::<Longitude>().is_err());
+}
+
+#[test]
+pub fn dms_display() -> anyhow::Result<()> {
+    assert_eq!(Latitude::new(50.72)?.dms().to_string(), "50°43'12\"N");
+    assert_eq!(Latitude::new(-0.5)?.dms().to_string(), "0°30'00\"S");
+    assert_eq!(Longitude::new(-3.53)?.dms().to_string(), "3°31'48\"W");
+    assert_eq!(
+        format!("{:.1}", Longitude::new(151.2093)?.dms()),
+        "151°12'33.5\"E"
+    );
+    // 59.996" rounds up into the next minute and degree
+    let longitude = Longitude::new(2.0 - 0.004 / 3600.0)?;
+    assert_eq!(format!("{:.2}", longitude.dms()), "2°00'00.00\"E");
+    Ok(())
+}
+
+#[test]
+pub fn geohash() -> anyhow::Result<()> {
+    let latitude = Latitude::new(57.64911)?;
+    let longitude = Longitude::new(10.40744)?;
+    let hash = Geohash::from_lat_lon(latitude, longitude, 11);
+    assert_eq!(hash.as_str(), "u4pruydqqvj");
+    assert_eq!(Geohash::from_lat_lon(latitude, longitude, 0).as_str(), "u");
+    assert_eq!(
+        Geohash::from_lat_lon(latitude, longitude, 20)
+            .as_str()
+            .len(),
+        12
+    );
+
+    let (lat, lon) = "EZS42".parse::<Geohash>()?.to_lat_lon();
+    assert!(close(lat.as_float(), 42.605, 1e-3));
+    assert!(close(lon.as_float(), -5.603, 1e-3));
+
+    let (lat, lon) = hash.to_lat_lon();
+    assert!(close(lat.as_float(), 57.64911, 1e-5));
+    assert!(close(lon.as_float(), 10.40744, 1e-5));
+
+    for s in ["", "abc", "u4pruydqqvj12", "u4p ru"] {
+        assert!(
+            matches!(s.parse::<Geohash>(), Err(Error::InvalidGeohash)),
+            "{s}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+pub fn maidenhead() -> anyhow::Result<()> {
+    let latitude = Latitude::new(48.14666)?;
+    let longitude = Longitude::new(11.60833)?;
+    assert_eq!(
+        Maidenhead::from_lat_lon(latitude, longitude, 3).as_str(),
+        "JN58td"
+    );
+    assert_eq!(
+        Maidenhead::from_lat_lon(latitude, longitude, 1).as_str(),
+        "JN"
+    );
+    assert_eq!(
+        Maidenhead::from_lat_lon(latitude, longitude, 4).as_str(),
+        "JN58td25"
+    );
+
+    // The edges of the world fall in the last field
+    let corner = Maidenhead::from_lat_lon(Latitude::new(90.0)?, Longitude::new(180.0)?, 2);
+    assert_eq!(corner.as_str(), "RR99");
+
+    let locator: Maidenhead = "io80FR".parse()?;
+    assert_eq!(locator.to_string(), "IO80fr");
+    let (lat, lon) = locator.to_lat_lon();
+    assert!(close(lat.as_float(), 50.0 + 17.5 / 24.0, 1e-9));
+    assert!(close(lon.as_float(), -4.0 + 5.5 / 12.0, 1e-9));
+
+    for s in [
+        "",
+        "I",
+        "IO8",
+        "SO80",
+        "IO8A",
+        "IO80fz",
+        "IO80fr2",
+        "IO80fr23aa",
+    ] {
+        assert!(
+            matches!(
+                s.parse::<Maidenhead>(),
+                Err(Error::InvalidMaidenheadLocator)
+            ),
+            "{s}"
+        );
+    }
+    Ok(())
+}
+
+/// Landmarks in each quadrant, as (longitude, latitude, altitude) like the GeoJSON API
+const LANDMARKS: [(&str, [f64; 3]); 4] = [
+    ("Eiffel Tower", [2.29448, 48.85837, 330.0]),
+    ("Statue of Liberty", [-74.04451, 40.68925, 93.0]),
+    ("Sydney Opera House", [151.21530, -33.85678, 4.0]),
+    ("Christ the Redeemer", [-43.21049, -22.95192, 700.0]),
+];
+
+fn format_landmarks(format: CoordinateFormat) -> anyhow::Result<Vec<String>> {
+    LANDMARKS
+        .iter()
+        .map(|(_, c)| Ok(Coordinates::try_from(*c)?.format(&format)))
+        .collect()
+}
+
+#[test]
+pub fn hemisphere_display() -> anyhow::Result<()> {
+    let expected = [
+        "48.858° N, 2.294° E 330m",
+        "40.689° N, 74.045° W 93m",
+        "33.857° S, 151.215° E 4m",
+        "22.952° S, 43.210° W 700m",
+    ];
+    for ((name, c), expected) in LANDMARKS.iter().zip(expected) {
+        let coordinates = Coordinates::try_from(*c)?;
+        assert_eq!(coordinates.to_string(), expected, "{name}");
+        assert_eq!(
+            coordinates.format(&CoordinateFormat::default()),
+            expected,
+            "{name}"
+        );
+    }
+    assert_eq!(Longitude::new(-3.474)?.to_string(), "3.474° W");
+    assert_eq!(format!("{:.1}", Longitude::new(3.474)?), "3.5° E");
+    Ok(())
+}
+
+#[test]
+pub fn decimal_notation() -> anyhow::Result<()> {
+    let format = CoordinateFormat {
+        notation: Notation::Decimal,
+        altitude: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        format_landmarks(format)?,
+        [
+            "48.858, 2.294",
+            "40.689, -74.045",
+            "-33.857, 151.215",
+            "-22.952, -43.210",
+        ]
+    );
+    let format = CoordinateFormat {
+        precision: Some(5),
+        ..format
+    };
+    assert_eq!(Latitude::new(-22.95192)?.format(&format), "-22.95192");
+    Ok(())
+}
+
+#[test]
+pub fn dms_notation() -> anyhow::Result<()> {
+    let format = CoordinateFormat {
+        notation: Notation::Dms,
+        precision: Some(1),
+        altitude: true,
+    };
+    assert_eq!(
+        format_landmarks(format)?,
+        [
+            "48°51'30.1\"N, 2°17'40.1\"E 330m",
+            "40°41'21.3\"N, 74°02'40.2\"W 93m",
+            "33°51'24.4\"S, 151°12'55.1\"E 4m",
+            "22°57'06.9\"S, 43°12'37.8\"W 700m",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn iso_6709_notation() -> anyhow::Result<()> {
+    let format = CoordinateFormat::new(Notation::Iso6709);
+    assert_eq!(
+        format_landmarks(format)?,
+        [
+            "+48.858+002.294+330CRSWGS_84/",
+            "+40.689-074.045+93CRSWGS_84/",
+            "-33.857+151.215+4CRSWGS_84/",
+            "-22.952-043.210+700CRSWGS_84/",
+        ]
+    );
+    let format = CoordinateFormat {
+        precision: Some(0),
+        altitude: false,
+        ..format
+    };
+    assert_eq!(
+        format_landmarks(format)?,
+        ["+49+002/", "+41-074/", "-34+151/", "-23-043/"]
+    );
+    assert_eq!(Longitude::new(-3.474)?.format(&format), "-003");
+    Ok(())
+}
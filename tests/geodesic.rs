@@ -0,0 +1,106 @@
+use rjw_metoffice::geodesic::{haversine_distance, initial_bearing, vincenty};
+use rjw_metoffice::units::{Coordinates, Degrees, Latitude, Longitude, Metres};
+use rjw_metoffice::{Forecast, Hourly};
+
+const SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+
+fn position(latitude: &str, longitude: &str) -> anyhow::Result<(Latitude, Longitude)> {
+    Ok((latitude.parse()?, longitude.parse()?))
+}
+
+fn close(a: f32, b: f32, tolerance: f32) -> bool {
+    (a - b).abs() < tolerance
+}
+
+/// Vincenty's own test line, from Flinders Peak to Buninyong in Victoria, Australia
+#[test]
+pub fn vincenty_flinders_peak_to_buninyong() -> anyhow::Result<()> {
+    let flinders_peak = position("37°57'03.72030\"S", "144°25'29.52440\"E")?;
+    let buninyong = position("37°39'10.15610\"S", "143°55'35.38390\"E")?;
+    let geodesic = vincenty(flinders_peak, buninyong).unwrap();
+    assert!(close(geodesic.distance.0, 54_972.27, 0.02));
+    let initial = 306.0 + 52.0 / 60.0 + 5.37 / 3600.0;
+    let last = 307.0 + 10.0 / 60.0 + 25.07 / 3600.0;
+    assert!(close(geodesic.initial_bearing.0, initial, 1e-4));
+    assert!(close(geodesic.final_bearing.0, last, 1e-4));
+    Ok(())
+}
+
+#[test]
+pub fn lands_end_to_john_o_groats() -> anyhow::Result<()> {
+    let lands_end = position("50 03 58.76 N", "005 42 53.10 W")?;
+    let john_o_groats = position("58 38 38.48 N", "003 04 12.34 W")?;
+
+    let haversine = haversine_distance(lands_end, john_o_groats);
+    assert!(close(haversine.0, 968_900.0, 100.0), "{haversine}");
+    let bearing = initial_bearing(lands_end, john_o_groats);
+    assert!(
+        close(bearing.0, 9.0 + 7.0 / 60.0 + 11.0 / 3600.0, 1e-3),
+        "{bearing}"
+    );
+
+    let geodesic = vincenty(lands_end, john_o_groats).unwrap();
+    assert!(
+        close(geodesic.distance.0, 969_954.2, 0.1),
+        "{}",
+        geodesic.distance
+    );
+    Ok(())
+}
+
+#[test]
+pub fn coincident_and_nearly_antipodal_points() -> anyhow::Result<()> {
+    let here = position("51.5", "-0.1")?;
+    let geodesic = vincenty(here, here).unwrap();
+    assert_eq!(geodesic.distance, Metres(0.0));
+    assert_eq!(haversine_distance(here, here), Metres(0.0));
+
+    // Vincenty's formula does not converge for these, so distance_to uses the haversine.
+    let a = Coordinates::try_from([0.0, 0.0, 0.0])?;
+    let b = Coordinates::try_from([179.7, 0.5, 0.0])?;
+    assert!(vincenty(a, b).is_none());
+    assert_eq!(a.distance_to(&b), haversine_distance(a, b));
+    assert_eq!(a.bearing_to(&b), initial_bearing(a, b));
+    Ok(())
+}
+
+#[test]
+pub fn bearings_in_each_direction() -> anyhow::Result<()> {
+    let origin = Coordinates::try_from([0.0, 0.0, 0.0])?;
+    for (lon, lat, expected) in [
+        (0.0, 1.0, 0.0),
+        (1.0, 0.0, 90.0),
+        (0.0, -1.0, 180.0),
+        (-1.0, 0.0, 270.0),
+    ] {
+        let other = Coordinates::try_from([lon, lat, 0.0])?;
+        assert!(
+            close(origin.bearing_to(&other).0, expected, 1e-3),
+            "{lon}, {lat}"
+        );
+        assert!(close(initial_bearing(origin, other).0, expected, 1e-3));
+    }
+    // A degree of longitude at the equator is longer than a degree of latitude.
+    let north_east = Coordinates::try_from([1.0, 1.0, 0.0])?;
+    assert!(origin.bearing_to(&north_east) > Degrees(45.0));
+    Ok(())
+}
+
+#[test]
+pub fn distance_from_forecast_site() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    let site = forecast.coordinates;
+    let london = Coordinates::try_from([-0.1276, 51.5072, 11.0])?;
+
+    let there = site.distance_to(&london);
+    let back = london.distance_to(&site);
+    assert!(close(there.0, back.0, 0.01));
+    // The spherical approximation is within 0.5% of the ellipsoidal distance.
+    let haversine = haversine_distance(site, london);
+    assert!(
+        (haversine.0 / there.0 - 1.0).abs() < 0.005,
+        "{haversine} vs {there}"
+    );
+    assert!(close(there.0, 249_850.0, 100.0), "{there}");
+    Ok(())
+}
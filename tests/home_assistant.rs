@@ -0,0 +1,82 @@
+use rjw_metoffice::home_assistant::condition;
+use rjw_metoffice::units::Conditions;
+use rjw_metoffice::{Daily, Forecast, Hourly};
+use serde_json::Value;
+
+const HOURLY_SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY_SAMPLE: &str = include_str!("global-spot-daily-sample.json");
+
+/// All conditions names accepted by Home Assistant weather entities
+const HA_CONDITIONS: &[&str] = &[
+    "clear-night",
+    "cloudy",
+    "exceptional",
+    "fog",
+    "hail",
+    "lightning",
+    "lightning-rainy",
+    "partlycloudy",
+    "pouring",
+    "rainy",
+    "snowy",
+    "snowy-rainy",
+    "sunny",
+    "windy",
+    "windy-variant",
+];
+
+#[test]
+pub fn every_condition_maps_to_a_home_assistant_condition() {
+    for code in (-1..=30).filter(|&c| c != 4) {
+        let c = Conditions::try_from(code).unwrap();
+        assert!(HA_CONDITIONS.contains(&condition(c)), "{c:?}");
+    }
+}
+
+#[test]
+pub fn clear_skies_depend_on_day_or_night() {
+    assert_eq!(condition(Conditions::SunnyDay), "sunny");
+    assert_eq!(condition(Conditions::ClearNight), "clear-night");
+    assert_eq!(condition(Conditions::PartlyCloudyNight), "partlycloudy");
+}
+
+#[test]
+pub fn hourly_entries() {
+    let f: Forecast<Hourly> = HOURLY_SAMPLE.parse().expect("Failed to parse");
+    let json: Value = serde_json::from_str(&f.to_home_assistant()).unwrap();
+    assert_eq!(json["native_pressure_unit"], "hPa");
+    let forecast = json["forecast"].as_array().unwrap();
+    assert_eq!(forecast.len(), 49);
+    let first = &forecast[0];
+    assert_eq!(first["datetime"], "2023-07-05T10:00:00Z");
+    assert_eq!(first["condition"], "pouring");
+    assert_eq!(first["native_pressure"], 1013.8);
+    assert_eq!(first["wind_bearing"], 282.0);
+    // Total precipitation is missing from the final hours.
+    assert!(forecast[48].get("native_precipitation").is_none());
+}
+
+#[test]
+pub fn daily_entries_skip_the_past_day() {
+    let f: Forecast<Daily> = DAILY_SAMPLE.parse().expect("Failed to parse");
+    let json: Value = serde_json::from_str(&f.to_home_assistant_daily()).unwrap();
+    let forecast = json["forecast"].as_array().unwrap();
+    assert_eq!(forecast.len(), 7);
+    assert_eq!(forecast[0]["datetime"], "2023-07-05T00:00:00Z");
+    assert_eq!(forecast[0]["condition"], "rainy");
+    assert!(forecast[0]["native_templow"].is_number());
+    assert!(forecast[0].get("is_daytime").is_none());
+}
+
+#[test]
+pub fn twice_daily_entries_alternate() {
+    let f: Forecast<Daily> = DAILY_SAMPLE.parse().expect("Failed to parse");
+    let json: Value = serde_json::from_str(&f.to_home_assistant_twice_daily()).unwrap();
+    let forecast = json["forecast"].as_array().unwrap();
+    assert_eq!(forecast.len(), 14);
+    assert_eq!(forecast[0]["is_daytime"], true);
+    assert_eq!(forecast[0]["datetime"], "2023-07-05T12:00:00Z");
+    assert_eq!(forecast[1]["is_daytime"], false);
+    assert_eq!(forecast[1]["datetime"], "2023-07-06T00:00:00Z");
+    assert_eq!(forecast[1]["condition"], "partlycloudy");
+}
@@ -0,0 +1,32 @@
+use rjw_metoffice::units::Conditions;
+
+#[test]
+pub fn every_condition_has_an_svg_icon() {
+    for code in (-1..=30).filter(|&c| c != 4) {
+        let c = Conditions::try_from(code).unwrap();
+        let svg = c.svg_icon();
+        let doc = roxmltree::Document::parse(svg)
+            .unwrap_or_else(|e| panic!("{c:?} icon is not valid XML: {e}"));
+        let root = doc.root_element();
+        assert_eq!(root.tag_name().name(), "svg", "{c:?}");
+        assert_eq!(
+            root.tag_name().namespace(),
+            Some("http://www.w3.org/2000/svg"),
+            "{c:?}"
+        );
+        assert_eq!(root.attribute("viewBox"), Some("0 0 64 64"), "{c:?}");
+        assert!(
+            root.children().any(|n| n.has_tag_name("title")),
+            "{c:?} icon has no title"
+        );
+    }
+    // Day and night variants have distinct icons.
+    assert_ne!(
+        Conditions::SunnyDay.svg_icon(),
+        Conditions::ClearNight.svg_icon()
+    );
+    assert_ne!(
+        Conditions::HailShowerDay.svg_icon(),
+        Conditions::HailShowerNight.svg_icon()
+    );
+}
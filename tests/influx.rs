@@ -0,0 +1,63 @@
+use rjw_metoffice::{Daily, Forecast, Hourly};
+
+const HOURLY_SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY_SAMPLE: &str = include_str!("global-spot-daily-sample.json");
+
+#[test]
+pub fn one_point_per_prediction() {
+    let f: Forecast<Hourly> = HOURLY_SAMPLE.parse().expect("Failed to parse");
+    let lines = f.to_line_protocol("weather");
+    assert_eq!(lines.lines().count(), f.predictions.len());
+}
+
+#[test]
+pub fn hourly_point_has_tags_fields_and_timestamp() {
+    let f: Forecast<Hourly> = HOURLY_SAMPLE.parse().expect("Failed to parse");
+    let lines = f.to_line_protocol("weather");
+    let first = lines.lines().next().unwrap();
+    assert!(first.starts_with(
+        "weather,location_name=Exeter\\ Met\\ Office,latitude=50.727,longitude=-3.474,\
+         predictions_made_at=2023-07-05T10:00:00Z conditions=15i,temperature=17.64,"
+    ));
+    assert!(first.contains(",pressure=101380i,uv_index=5i "));
+    assert!(first.ends_with(" 1688551200000000000"));
+}
+
+#[test]
+pub fn missing_hourly_fields_are_omitted() {
+    let f: Forecast<Hourly> = HOURLY_SAMPLE.parse().expect("Failed to parse");
+    let lines = f.to_line_protocol("weather");
+    let last = lines.lines().last().unwrap();
+    assert!(!last.contains("temperature_maximum="));
+    assert!(!last.contains("precipitation_total="));
+    assert!(last.contains(" conditions="));
+}
+
+#[test]
+pub fn daily_fields_are_flattened() {
+    let f: Forecast<Daily> = DAILY_SAMPLE.parse().expect("Failed to parse");
+    let lines = f.to_line_protocol("daily");
+    let mut lines = lines.lines();
+    let past = lines.next().unwrap();
+    assert!(!past.contains("day_conditions="));
+    assert!(past.contains(" day_temperature_maximum="));
+    assert!(past.contains(",day_temperature_feels_like_maximum_upper_bound="));
+    assert!(past.contains(",night_conditions=7i,"));
+    let future = lines.next().unwrap();
+    assert!(future.contains(" day_conditions=12i,"));
+    assert!(future.contains(",day_uv_index_maximum="));
+    assert!(future.contains(",night_temperature_minimum_lower_bound="));
+}
+
+#[test]
+pub fn awkward_location_names() {
+    let mut f: Forecast<Hourly> = HOURLY_SAMPLE.parse().expect("Failed to parse");
+    f.location_name = String::new();
+    let lines = f.to_line_protocol("weather");
+    assert!(lines.starts_with("weather,latitude=50.727,"));
+
+    f.location_name = "Exeter\nMet Office".into();
+    let lines = f.to_line_protocol("weather");
+    assert!(lines.starts_with("weather,location_name=Exeter\\ Met\\ Office,"));
+    assert_eq!(lines.lines().count(), f.predictions.len());
+}
@@ -0,0 +1,208 @@
+use jiff::{SignedDuration, Timestamp, Zoned};
+use rjw_metoffice::units::{
+    Celsius, Conditions, Coordinates, Degrees, Metres, MetresPerSecond, Millimetres, Pascals,
+    Percentage, UvIndex, Visibility,
+};
+use rjw_metoffice::{Daily, Error, Forecast, Hourly, ThreeHourly};
+
+const HOURLY: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY: &str = include_str!("global-spot-daily-sample.json");
+
+fn ts(s: &str) -> Timestamp {
+    s.parse().unwrap()
+}
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+#[test]
+pub fn at_returns_covering_prediction() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    for (time, expected) in [
+        ("2023-07-05T10:00Z", "2023-07-05T10:00:00+00:00[UTC]"),
+        ("2023-07-05T15:20Z", "2023-07-05T15:00:00+00:00[UTC]"),
+        ("2023-07-05T15:59:59Z", "2023-07-05T15:00:00+00:00[UTC]"),
+        ("2023-07-05T16:00Z", "2023-07-05T16:00:00+00:00[UTC]"),
+        ("2023-07-07T10:30Z", "2023-07-07T10:00:00+00:00[UTC]"),
+    ] {
+        assert_eq!(forecast.at(ts(time))?.time.to_string(), expected);
+    }
+    Ok(())
+}
+
+#[test]
+pub fn at_accepts_zoned_times() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let time: jiff::Zoned = "2023-07-05T16:20+01:00[Europe/London]".parse()?;
+    assert_eq!(forecast.at(&time)?.time.hour(), 15);
+    Ok(())
+}
+
+#[test]
+pub fn at_outside_forecast() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    for time in ["2023-07-05T09:59:59Z", "2023-07-07T11:00Z"] {
+        let result = forecast.at(ts(time));
+        assert!(
+            matches!(result, Err(Error::TimeOutsideForecast(t)) if t == ts(time)),
+            "{time}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+pub fn at_daily() -> anyhow::Result<()> {
+    let forecast: Forecast<Daily> = DAILY.parse()?;
+    let first = forecast.predictions[0].time.timestamp();
+    let day = forecast.at(first + jiff::SignedDuration::from_hours(30))?;
+    assert_eq!(day.time, forecast.predictions[1].time);
+    Ok(())
+}
+
+#[test]
+pub fn interpolate_between_hours() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    // 15:00 is 19.23°C and 101420 Pa, 16:00 is 18.4°C and 101450 Pa.
+    let h = forecast.interpolate(ts("2023-07-05T15:20Z"))?;
+    assert_eq!(h.time.to_string(), "2023-07-05T15:20:00+00:00[UTC]");
+    assert!(close(h.temperature.0, 19.23 + (18.4 - 19.23) / 3.0));
+    assert_eq!(h.pressure, Pascals(101430));
+    assert_eq!(h.conditions, Conditions::Cloudy);
+    assert!(h.temperature_maximum.is_some());
+    Ok(())
+}
+
+#[test]
+pub fn interpolate_exactly_on_the_hour() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    for (i, p) in forecast.predictions.iter().enumerate() {
+        let h = forecast.interpolate(&p.time)?;
+        assert_eq!(h.time, p.time, "{i}");
+        assert_eq!(h.temperature, p.temperature, "{i}");
+        assert_eq!(h.conditions, p.conditions, "{i}");
+        assert_eq!(h.temperature_maximum, p.temperature_maximum, "{i}");
+        assert!(close(h.wind_speed.0, p.wind_speed.0), "{i}");
+    }
+    Ok(())
+}
+
+#[test]
+pub fn interpolate_wind_as_vectors() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    // 15:00 is 5.68 m/s from 275°, 16:00 is 6.39 m/s from 290°.
+    let h = forecast.interpolate(ts("2023-07-05T15:30Z"))?;
+    assert!(h.wind_direction > Degrees(275.0) && h.wind_direction < Degrees(290.0));
+    assert!(h.wind_speed < MetresPerSecond((5.68 + 6.39) / 2.0));
+    assert!(h.wind_speed > MetresPerSecond(5.68));
+    Ok(())
+}
+
+#[test]
+pub fn interpolate_categories_from_nearest_hour() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    // 16:00 is cloudy and 17:00 is partly cloudy.
+    let before = forecast.interpolate(ts("2023-07-05T16:29Z"))?;
+    let after = forecast.interpolate(ts("2023-07-05T16:31Z"))?;
+    assert_eq!(before.conditions, Conditions::Cloudy);
+    assert_eq!(after.conditions, Conditions::PartlyCloudyDay);
+    Ok(())
+}
+
+#[test]
+pub fn interpolate_missing_optional_fields() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    // The maximum temperature is missing from 09:00 onwards on the final day.
+    let h = forecast.interpolate(ts("2023-07-07T08:30Z"))?;
+    assert_eq!(h.temperature_maximum, None);
+    let h = forecast.interpolate(ts("2023-07-07T07:30Z"))?;
+    assert!(close(
+        h.temperature_maximum.unwrap().0,
+        (17.21 + 18.29) / 2.0
+    ));
+    Ok(())
+}
+
+#[test]
+pub fn interpolate_outside_forecast() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    // Unlike `at`, there is nothing to interpolate towards after the last hour.
+    for time in ["2023-07-05T09:00Z", "2023-07-07T10:01Z"] {
+        assert!(matches!(
+            forecast.interpolate(ts(time)),
+            Err(Error::TimeOutsideForecast(_))
+        ));
+    }
+    Ok(())
+}
+
+fn three_hourly(
+    time: Zoned,
+    conditions: Conditions,
+    temperature: f32,
+    pressure: u32,
+) -> ThreeHourly {
+    ThreeHourly {
+        time,
+        conditions,
+        temperature_maximum: Celsius(temperature + 1.0),
+        temperature_minimum: Celsius(temperature - 1.0),
+        temperature_feels_like: Celsius(temperature - 2.0),
+        wind_speed: MetresPerSecond(4.0),
+        wind_direction: Degrees(180.0),
+        wind_gust_speed: MetresPerSecond(8.0),
+        wind_gust_three_hourly_maximum: MetresPerSecond(9.0),
+        visibility: Visibility(20_000.0),
+        relative_humidity: Percentage(80.0),
+        pressure: Pascals(pressure),
+        uv_index: UvIndex(1),
+        precipitation_total: Millimetres(0.0),
+        snow_total: Millimetres(0.0),
+        precipitation_probability: Percentage(10.0),
+        rain_probability: Percentage(10.0),
+        heavy_rain_probability: Percentage(0.0),
+        snow_probability: Percentage(0.0),
+        heavy_snow_probability: Percentage(0.0),
+        hail_probability: Percentage(0.0),
+        lightning_probability: Percentage(0.0),
+    }
+}
+
+#[test]
+pub fn interpolate_three_hourly() -> anyhow::Result<()> {
+    let first: Zoned = "2024-01-15T00:00Z[UTC]".parse()?;
+    let forecast = Forecast {
+        location_name: "Exeter".into(),
+        coordinates: Coordinates::try_from([-3.474, 50.727, 27.0])?,
+        requested_point_distance: Metres(0.0),
+        predictions_made_at: first.clone(),
+        predictions: vec![
+            three_hourly(first.clone(), Conditions::Overcast, 6.0, 101_000),
+            three_hourly(
+                &first + SignedDuration::from_hours(3),
+                Conditions::LightRain,
+                9.0,
+                100_700,
+            ),
+        ],
+    };
+
+    let t = forecast.interpolate(ts("2024-01-15T01:00Z"))?;
+    assert_eq!(t.time.to_string(), "2024-01-15T01:00:00+00:00[UTC]");
+    assert!(close(t.temperature_maximum.0, 8.0));
+    assert!(close(t.temperature_minimum.0, 6.0));
+    assert_eq!(t.pressure, Pascals(100_900));
+    // Conditions come from the nearer prediction.
+    assert_eq!(t.conditions, Conditions::Overcast);
+    let t = forecast.interpolate(ts("2024-01-15T02:00Z"))?;
+    assert_eq!(t.conditions, Conditions::LightRain);
+
+    for time in ["2024-01-14T23:59Z", "2024-01-15T03:01Z"] {
+        assert!(matches!(
+            forecast.interpolate(ts(time)),
+            Err(Error::TimeOutsideForecast(t)) if t == ts(time)
+        ));
+    }
+    Ok(())
+}
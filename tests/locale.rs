@@ -0,0 +1,151 @@
+use rjw_metoffice::compass::CompassPoints;
+use rjw_metoffice::locale::Locale;
+use rjw_metoffice::units::{
+    Conditions, Degrees, DistanceUnit, SpeedUnit, TemperatureUnit, UvCategory,
+};
+
+const TRANSLATED: [Locale; 4] = [
+    Locale::Welsh,
+    Locale::French,
+    Locale::German,
+    Locale::Spanish,
+];
+
+fn all_conditions() -> impl Iterator<Item = Conditions> {
+    (-1..=30).filter_map(|code| Conditions::try_from(code).ok())
+}
+
+#[test]
+pub fn english_matches_display() {
+    for conditions in all_conditions() {
+        assert_eq!(
+            Locale::English.conditions(conditions),
+            conditions.to_string()
+        );
+    }
+    let category = UvCategory::VeryHigh;
+    assert_eq!(Locale::English.uv_category(category), category.label());
+    assert_eq!(Locale::English.uv_advice(category), category.advice());
+}
+
+#[test]
+pub fn every_condition_is_translated() {
+    for locale in TRANSLATED {
+        for conditions in all_conditions() {
+            assert_ne!(
+                locale.conditions(conditions),
+                conditions.to_string(),
+                "{locale} {conditions:?}"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn every_uv_category_is_translated() {
+    use UvCategory::*;
+    for locale in TRANSLATED {
+        for category in [Low, Moderate, High, VeryHigh, Extreme] {
+            assert_ne!(
+                locale.uv_category(category),
+                category.label(),
+                "{locale} {category:?}"
+            );
+            assert_ne!(
+                locale.uv_advice(category),
+                category.advice(),
+                "{locale} {category:?}"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn conditions() {
+    let c = Conditions::LightSnowShowerDay;
+    assert_eq!(Locale::Welsh.conditions(c), "Cawod ysgafn o eira");
+    assert_eq!(Locale::French.conditions(c), "Faible averse de neige");
+    assert_eq!(Locale::German.conditions(c), "Leichter Schneeschauer");
+    assert_eq!(Locale::Spanish.conditions(c), "Chubasco de nieve débil");
+}
+
+#[test]
+pub fn uv() {
+    assert_eq!(
+        Locale::French.uv_category(UvCategory::VeryHigh),
+        "Très élevé"
+    );
+    assert_eq!(Locale::German.uv_category(UvCategory::Low), "Niedrig");
+    assert!(
+        Locale::Spanish
+            .uv_advice(UvCategory::Extreme)
+            .starts_with("Evite")
+    );
+}
+
+#[test]
+pub fn compass() {
+    let wsw = Degrees(247.5);
+    assert_eq!(Locale::English.compass(wsw, CompassPoints::Sixteen), "WSW");
+    assert_eq!(Locale::French.compass(wsw, CompassPoints::Sixteen), "OSO");
+    assert_eq!(Locale::Spanish.compass(wsw, CompassPoints::Sixteen), "OSO");
+    assert_eq!(Locale::German.compass(wsw, CompassPoints::Sixteen), "WSW");
+    assert_eq!(
+        Locale::German.compass(Degrees(90.0), CompassPoints::Eight),
+        "O"
+    );
+
+    let welsh: Vec<&str> = (0..16)
+        .map(|i| Locale::Welsh.compass(Degrees(i as f32 * 22.5), CompassPoints::Sixteen))
+        .collect();
+    assert_eq!(
+        welsh,
+        [
+            "G", "GGDd", "GDd", "DwGDd", "Dw", "DwDDd", "DDd", "DDDd", "D", "DDGn", "DGn", "GnDGn",
+            "Gn", "GnGGn", "GGn", "GGGn"
+        ]
+    );
+}
+
+#[test]
+pub fn missing_strings_fall_back_to_english() {
+    assert_eq!(
+        Locale::Welsh.compass(Degrees(90.0), CompassPoints::Eight),
+        "Dw"
+    );
+    assert_eq!(
+        Locale::Welsh.compass(Degrees(11.25), CompassPoints::ThirtyTwo),
+        "NbE"
+    );
+    assert_eq!(
+        Locale::French.compass(Degrees(11.25), CompassPoints::ThirtyTwo),
+        "NbE"
+    );
+    assert_eq!(Locale::German.speed_unit(SpeedUnit::MetresPerSecond), "m/s");
+}
+
+#[test]
+pub fn units() {
+    assert_eq!(Locale::Welsh.speed_unit(SpeedUnit::MilesPerHour), "mya");
+    assert_eq!(Locale::French.speed_unit(SpeedUnit::Knots), "nd");
+    assert_eq!(
+        Locale::German.distance_unit(DistanceUnit::NauticalMiles),
+        "sm"
+    );
+    assert_eq!(
+        Locale::Spanish.temperature_unit(TemperatureUnit::Celsius),
+        "°C"
+    );
+}
+
+#[test]
+pub fn tags() {
+    assert_eq!(Locale::from_tag("cy-GB"), Some(Locale::Welsh));
+    assert_eq!(Locale::from_tag("FR"), Some(Locale::French));
+    assert_eq!(Locale::from_tag("de_AT"), Some(Locale::German));
+    assert_eq!(Locale::from_tag("es"), Some(Locale::Spanish));
+    assert_eq!(Locale::from_tag("en-US"), Some(Locale::English));
+    assert_eq!(Locale::from_tag("ga"), None);
+    assert_eq!(Locale::from_tag(""), None);
+    assert_eq!(Locale::Welsh.tag(), "cy");
+}
@@ -0,0 +1,44 @@
+//! Meteogram output is compared against reference SVG files.
+//!
+//! After an intentional change to the rendering, regenerate the references by running the tests
+//! with `UPDATE_REFERENCES=1` set in the environment, and check the new images by eye.
+use jiff::tz::TimeZone;
+use rjw_metoffice::meteogram::Options;
+use rjw_metoffice::units::{PressureUnit, SpeedUnit, TemperatureUnit};
+use rjw_metoffice::{Forecast, Hourly};
+
+const SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+
+fn compare_with_reference(svg: &str, reference: &str) {
+    let path = format!("{}/tests/{reference}", env!("CARGO_MANIFEST_DIR"));
+    if std::env::var_os("UPDATE_REFERENCES").is_some() {
+        std::fs::write(&path, svg).unwrap();
+    }
+    let expected = std::fs::read_to_string(&path).expect("Missing reference file");
+    assert!(
+        svg == expected,
+        "{reference} does not match the rendered SVG"
+    );
+}
+
+#[test]
+pub fn hourly_default_options() {
+    let f: Forecast<Hourly> = SAMPLE.parse().expect("Failed to parse");
+    let svg = f.to_meteogram(&Options::default());
+    compare_with_reference(&svg, "meteogram-hourly.svg");
+}
+
+#[test]
+pub fn hourly_us_units_local_time() {
+    let f: Forecast<Hourly> = SAMPLE.parse().expect("Failed to parse");
+    let options = Options {
+        width: 640,
+        height: 320,
+        time_zone: TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap(),
+        temperature_unit: TemperatureUnit::Fahrenheit,
+        speed_unit: SpeedUnit::MilesPerHour,
+        pressure_unit: PressureUnit::InchesOfMercury,
+    };
+    let svg = f.to_meteogram(&options);
+    compare_with_reference(&svg, "meteogram-hourly-us.svg");
+}
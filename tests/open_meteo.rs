@@ -0,0 +1,63 @@
+use rjw_metoffice::open_meteo::weather_code;
+use rjw_metoffice::units::Conditions;
+use rjw_metoffice::{Daily, Forecast, Hourly};
+use serde_json::Value;
+
+const HOURLY_SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY_SAMPLE: &str = include_str!("global-spot-daily-sample.json");
+
+/// WMO weather interpretation codes used by Open-Meteo
+const OPEN_METEO_CODES: &[u8] = &[
+    0, 1, 2, 3, 45, 48, 51, 53, 55, 56, 57, 61, 63, 65, 66, 67, 71, 73, 75, 77, 80, 81, 82, 85, 86,
+    95, 96, 99,
+];
+
+#[test]
+pub fn every_condition_maps_to_an_open_meteo_code() {
+    for code in (-1..=30).filter(|&c| c != 4) {
+        let c = Conditions::try_from(code).unwrap();
+        assert!(OPEN_METEO_CODES.contains(&weather_code(c)), "{c:?}");
+    }
+}
+
+#[test]
+pub fn hourly_arrays_are_parallel() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY_SAMPLE.parse()?;
+    let json: Value = serde_json::from_str(&forecast.to_open_meteo())?;
+
+    assert_eq!(json["timezone"], "GMT");
+    assert_eq!(json["utc_offset_seconds"], 0);
+    assert_eq!(json["elevation"], 27.0);
+    assert_eq!(json["hourly_units"]["pressure_msl"], "hPa");
+    assert_eq!(json["hourly_units"]["wind_speed_10m"], "m/s");
+
+    let hourly = json["hourly"].as_object().unwrap();
+    for (name, values) in hourly {
+        assert_eq!(values.as_array().unwrap().len(), 49, "{name}");
+        assert!(json["hourly_units"].get(name).is_some(), "{name}");
+    }
+    assert_eq!(hourly["time"][0], "2023-07-05T10:00");
+    assert_eq!(hourly["time"][48], "2023-07-07T10:00");
+    // Heavy rain
+    assert_eq!(hourly["weather_code"][0], 65);
+    // Totals are missing from the final hours of the forecast.
+    assert!(hourly["precipitation"][48].is_null());
+    Ok(())
+}
+
+#[test]
+pub fn daily_past_day_has_nulls() -> anyhow::Result<()> {
+    let forecast: Forecast<Daily> = DAILY_SAMPLE.parse()?;
+    let json: Value = serde_json::from_str(&forecast.to_open_meteo())?;
+
+    let daily = &json["daily"];
+    assert_eq!(daily["time"].as_array().unwrap().len(), 8);
+    assert_eq!(daily["time"][0], "2023-07-04");
+    assert!(daily["weather_code"][0].is_null());
+    assert!(daily["uv_index_max"][0].is_null());
+    assert!(daily["temperature_2m_max"][0].is_number());
+    // Light rain
+    assert_eq!(daily["weather_code"][1], 61);
+    assert!(json.get("hourly").is_none());
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use jiff::SignedDuration;
+use rjw_metoffice::{Forecast, Hourly};
+
+const SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+
+#[test]
+pub fn gauge_samples_are_labelled() {
+    let f: Forecast<Hourly> = SAMPLE.parse().expect("Failed to parse");
+    let text = f.to_openmetrics(&[SignedDuration::ZERO]);
+    let expected = "metoffice_temperature_celsius{location_name=\"Exeter Met Office\",\
+                    latitude=\"50.727\",longitude=\"-3.474\",lead_time=\"0s\"} 17.64";
+    assert!(text.lines().any(|l| l == expected), "{text}");
+}
+
+#[test]
+pub fn families_have_type_and_unit() {
+    let f: Forecast<Hourly> = SAMPLE.parse().expect("Failed to parse");
+    let text = f.to_openmetrics(&[SignedDuration::ZERO, SignedDuration::from_hours(3)]);
+    let mut lines = text
+        .lines()
+        .skip_while(|l| !l.contains("wind_speed_meters_per_second"));
+    assert_eq!(
+        lines.next(),
+        Some("# TYPE metoffice_wind_speed_meters_per_second gauge")
+    );
+    assert_eq!(
+        lines.next(),
+        Some("# UNIT metoffice_wind_speed_meters_per_second meters_per_second")
+    );
+    assert!(lines.next().unwrap().ends_with("lead_time=\"0s\"} 4.84"));
+    assert!(lines.next().unwrap().contains("lead_time=\"3h\""));
+}
+
+#[test]
+pub fn model_run_timestamp_and_eof() {
+    let f: Forecast<Hourly> = SAMPLE.parse().expect("Failed to parse");
+    let text = f.to_openmetrics(&[]);
+    let expected = "metoffice_predictions_made_at_timestamp_seconds{location_name=\"Exeter Met Office\",\
+                    latitude=\"50.727\",longitude=\"-3.474\"} 1688551200";
+    assert!(text.lines().any(|l| l == expected), "{text}");
+    assert!(text.ends_with("# EOF\n"));
+}
+
+#[test]
+pub fn missing_fields_and_lead_times_are_skipped() {
+    let f: Forecast<Hourly> = SAMPLE.parse().expect("Failed to parse");
+    // The final hour of the sample has no maximum temperature.
+    let text = f.to_openmetrics(&[
+        SignedDuration::from_hours(48),
+        SignedDuration::from_hours(49),
+    ]);
+    assert!(!text.contains("temperature_maximum"));
+    assert!(!text.contains("lead_time=\"49h\""));
+    assert!(text.contains("lead_time=\"48h\""));
+
+    // The first prediction is for the time of the model run.
+    let text = f.to_openmetrics(&[SignedDuration::from_hours(-1)]);
+    assert!(!text.contains("lead_time="));
+}
+
+#[cfg(feature = "std")]
+#[test]
+pub fn serve_answers_get_and_head() -> anyhow::Result<()> {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    std::thread::spawn(move || rjw_metoffice::openmetrics::serve(&listener, || "# EOF\n".into()));
+
+    let request = |request: &str| -> anyhow::Result<String> {
+        let mut stream = TcpStream::connect(address)?;
+        stream.write_all(request.as_bytes())?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        Ok(response)
+    };
+
+    // A client that never sends its request must not hold up the scrapes after it.
+    let _silent = TcpStream::connect(address)?;
+
+    let get = request("GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+    assert!(get.starts_with("HTTP/1.1 200 OK\r\n"), "{get}");
+    assert!(get.contains("Content-Length: 6\r\n"));
+    assert!(get.ends_with("\r\n\r\n# EOF\n"));
+
+    let head = request("HEAD /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+    assert!(head.starts_with("HTTP/1.1 200 OK\r\n"), "{head}");
+    assert!(head.contains("Content-Length: 6\r\n"));
+    assert!(head.ends_with("\r\n\r\n"));
+
+    let other = request("GET / HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+    assert!(other.starts_with("HTTP/1.1 404 Not Found\r\n"), "{other}");
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use jiff::{SignedDuration, Zoned};
+use rjw_metoffice::precipitation::EpisodeThresholds;
+use rjw_metoffice::units::{
+    Celsius, Conditions, Coordinates, Degrees, Metres, MetresPerSecond, Millimetres,
+    MillimetresPerHour, Pascals, Percentage, PrecipitationKind, UvIndex, Visibility,
+};
+use rjw_metoffice::{Forecast, Hourly, ThreeHourly};
+
+const HOURLY: &str = include_str!("global-spot-hourly-sample.json");
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+fn hhmm(time: &Zoned) -> String {
+    time.strftime("%d %H:%M").to_string()
+}
+
+#[test]
+pub fn hourly_episodes() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let episodes = forecast.precipitation_episodes(&EpisodeThresholds::default());
+    assert_eq!(episodes.len(), 2);
+
+    let first = &episodes[0];
+    assert_eq!(hhmm(&first.start), "05 10:00");
+    assert_eq!(hhmm(&first.end), "05 13:00");
+    assert_eq!(first.duration(), SignedDuration::from_hours(3));
+    assert_eq!(first.peak_rate, MillimetresPerHour(1.41));
+    assert!(close(first.total.0, 0.09 + 0.17 + 0.11));
+    assert_eq!(first.kind, PrecipitationKind::Rain);
+    assert!(close(first.confidence.0, (81.0 + 46.0 + 39.0) / 3.0));
+
+    let second = &episodes[1];
+    assert_eq!(hhmm(&second.start), "06 10:00");
+    assert_eq!(hhmm(&second.end), "06 12:00");
+    assert_eq!(second.peak_rate, MillimetresPerHour(0.44));
+    Ok(())
+}
+
+#[test]
+pub fn probability_threshold() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let thresholds = EpisodeThresholds {
+        probability: Percentage(50.0),
+        ..Default::default()
+    };
+    let episodes = forecast.precipitation_episodes(&thresholds);
+    assert_eq!(episodes.len(), 1);
+    assert_eq!(hhmm(&episodes[0].start), "05 10:00");
+    assert_eq!(hhmm(&episodes[0].end), "05 11:00");
+    assert!(close(episodes[0].confidence.0, 81.0));
+    Ok(())
+}
+
+#[test]
+pub fn dry_spells_are_bridged() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = HOURLY.parse()?;
+    let thresholds = EpisodeThresholds {
+        gap: SignedDuration::from_hours(24),
+        ..Default::default()
+    };
+    let episodes = forecast.precipitation_episodes(&thresholds);
+    assert_eq!(episodes.len(), 1);
+    let episode = &episodes[0];
+    assert_eq!(hhmm(&episode.start), "05 10:00");
+    assert_eq!(hhmm(&episode.end), "06 12:00");
+    // The dry hours in between contribute nothing to the total.
+    assert!(close(episode.total.0, 0.37 + 0.14));
+    // Confidence is over the five wet hours only.
+    assert!(close(
+        episode.confidence.0,
+        (81.0 + 46.0 + 39.0 + 49.0 + 35.0) / 5.0
+    ));
+
+    // The two episodes are 21 hours apart.
+    let thresholds = EpisodeThresholds {
+        gap: SignedDuration::from_hours(21),
+        ..Default::default()
+    };
+    assert_eq!(forecast.precipitation_episodes(&thresholds).len(), 1);
+    let thresholds = EpisodeThresholds {
+        gap: SignedDuration::from_mins(20 * 60 + 59),
+        ..Default::default()
+    };
+    assert_eq!(forecast.precipitation_episodes(&thresholds).len(), 2);
+    Ok(())
+}
+
+fn three_hourly(time: Zoned, conditions: Conditions, total: f32, probability: f32) -> ThreeHourly {
+    ThreeHourly {
+        time,
+        conditions,
+        temperature_maximum: Celsius(1.0),
+        temperature_minimum: Celsius(-2.0),
+        temperature_feels_like: Celsius(-4.0),
+        wind_speed: MetresPerSecond(4.0),
+        wind_direction: Degrees(20.0),
+        wind_gust_speed: MetresPerSecond(8.0),
+        wind_gust_three_hourly_maximum: MetresPerSecond(9.0),
+        visibility: Visibility(5_000.0),
+        relative_humidity: Percentage(90.0),
+        pressure: Pascals(100_500),
+        uv_index: UvIndex(0),
+        precipitation_total: Millimetres(total),
+        snow_total: Millimetres(total),
+        precipitation_probability: Percentage(probability),
+        rain_probability: Percentage(0.0),
+        heavy_rain_probability: Percentage(0.0),
+        snow_probability: Percentage(probability),
+        heavy_snow_probability: Percentage(0.0),
+        hail_probability: Percentage(0.0),
+        lightning_probability: Percentage(0.0),
+    }
+}
+
+#[test]
+pub fn three_hourly_episodes() -> anyhow::Result<()> {
+    let first: Zoned = "2024-01-15T00:00Z[UTC]".parse()?;
+    let at = |i: i64| &first + SignedDuration::from_hours(3 * i);
+    let forecast = Forecast {
+        location_name: "Braemar".into(),
+        coordinates: Coordinates::try_from([-3.396, 57.006, 339.0])?,
+        requested_point_distance: Metres(120.0),
+        predictions_made_at: first.clone(),
+        predictions: vec![
+            three_hourly(at(0), Conditions::Overcast, 0.0, 10.0),
+            three_hourly(at(1), Conditions::LightSnow, 3.0, 70.0),
+            three_hourly(at(2), Conditions::Sleet, 1.5, 60.0),
+            three_hourly(at(3), Conditions::HeavySnow, 6.0, 90.0),
+            three_hourly(at(4), Conditions::Cloudy, 0.0, 10.0),
+            three_hourly(at(5), Conditions::Cloudy, 0.0, 10.0),
+            // Precipitation expected, but not in the conditions.
+            three_hourly(at(6), Conditions::Cloudy, 0.9, 40.0),
+        ],
+    };
+    let episodes = forecast.precipitation_episodes(&EpisodeThresholds::default());
+    assert_eq!(episodes.len(), 2);
+
+    let snow = &episodes[0];
+    assert_eq!(hhmm(&snow.start), "15 03:00");
+    assert_eq!(hhmm(&snow.end), "15 12:00");
+    assert!(close(snow.peak_rate.0, 2.0));
+    assert!(close(snow.total.0, 10.5));
+    assert_eq!(snow.kind, PrecipitationKind::Snow);
+    assert!(close(snow.confidence.0, (70.0 + 60.0 + 90.0) / 3.0));
+
+    let unknown = &episodes[1];
+    assert_eq!(hhmm(&unknown.start), "15 18:00");
+    assert!(close(unknown.peak_rate.0, 0.3));
+    assert_eq!(unknown.kind, PrecipitationKind::None);
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use jiff::tz::TimeZone;
+use jiff::{SignedDuration, Zoned};
+use rjw_metoffice::resample::DAY_PARTS;
+use rjw_metoffice::units::{Conditions, Millimetres};
+use rjw_metoffice::{Forecast, Hourly};
+
+const SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+#[test]
+pub fn fixed_length_windows() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    let windows = forecast.resample_every(SignedDuration::from_hours(3));
+    // 49 hours make 16 full windows and one with only the final hour.
+    assert_eq!(windows.len(), 17);
+    assert!(windows[..16].iter().all(|w| w.predictions == 3));
+    assert_eq!(windows[16].predictions, 1);
+    assert_eq!(windows[0].start, forecast.predictions[0].time);
+    assert_eq!(windows[0].end, forecast.predictions[3].time);
+
+    let hours = &forecast.predictions[..3];
+    let first = &windows[0];
+    let temperatures = hours.iter().map(|h| h.temperature.0);
+    assert!(close(
+        first.temperature_mean.0,
+        temperatures.clone().sum::<f32>() / 3.0
+    ));
+    let low = hours
+        .iter()
+        .flat_map(|h| [Some(h.temperature), h.temperature_minimum])
+        .flatten()
+        .map(|t| t.0)
+        .fold(f32::MAX, f32::min);
+    assert_eq!(first.temperature_minimum.0, low);
+    let rain: f32 = hours.iter().map(|h| h.precipitation_total.unwrap().0).sum();
+    assert!(close(first.precipitation_total.unwrap().0, rain));
+    let gust = hours
+        .iter()
+        .map(|h| {
+            h.wind_gust_hourly_maximum_speed
+                .unwrap()
+                .0
+                .max(h.wind_gust_speed.0)
+        })
+        .fold(0.0, f32::max);
+    assert_eq!(first.wind_gust_maximum.0, gust);
+    Ok(())
+}
+
+#[test]
+pub fn missing_optional_fields() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    // The final three hours have no precipitation or snow totals.
+    let windows = forecast.resample_every(SignedDuration::from_hours(6));
+    let (last, rest) = windows.split_last().unwrap();
+    assert_eq!(last.predictions, 1);
+    assert_eq!(last.precipitation_total, None);
+    assert_eq!(last.snow_total, None);
+    let before = rest.last().unwrap();
+    assert_eq!(before.predictions, 6);
+    assert_eq!(
+        before.precipitation_total, None,
+        "partly missing is unknown"
+    );
+    assert!(
+        rest[..rest.len() - 1]
+            .iter()
+            .all(|w| w.snow_total.is_some())
+    );
+    // Maximum temperatures and gusts fall back to the hourly values.
+    assert!(last.temperature_maximum.0 >= forecast.predictions[48].temperature.0);
+    assert!(last.wind_gust_maximum.0 >= forecast.predictions[48].wind_gust_speed.0);
+    Ok(())
+}
+
+#[test]
+pub fn explicit_windows() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    let zoned = |s: &str| -> Zoned { s.parse().unwrap() };
+    let windows = forecast.resample([
+        (
+            zoned("2023-07-04T00:00Z[UTC]"),
+            zoned("2023-07-05T00:00Z[UTC]"),
+        ),
+        (
+            zoned("2023-07-05T10:00Z[UTC]"),
+            zoned("2023-07-05T10:30Z[UTC]"),
+        ),
+        (
+            zoned("2023-07-06T00:00Z[UTC]"),
+            zoned("2023-07-07T00:00Z[UTC]"),
+        ),
+    ]);
+    assert_eq!(windows.len(), 2, "window before the forecast is skipped");
+    assert_eq!(windows[0].predictions, 1);
+    assert_eq!(windows[1].predictions, 24);
+    let day = &forecast.predictions[14..38];
+    assert_eq!(windows[1].start, day[0].time);
+    let rain: f32 = day.iter().map(|h| h.precipitation_total.unwrap().0).sum();
+    let Some(Millimetres(total)) = windows[1].precipitation_total else {
+        panic!("missing precipitation total");
+    };
+    assert!(close(total, rain));
+    Ok(())
+}
+
+#[test]
+pub fn most_significant_conditions() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    for window in forecast.resample_every(SignedDuration::from_hours(4)) {
+        let hours = forecast
+            .predictions
+            .iter()
+            .filter(|h| h.time >= window.start && h.time < window.end);
+        let worst = hours
+            .clone()
+            .map(|h| h.conditions.severity())
+            .max()
+            .unwrap();
+        assert_eq!(window.conditions.severity(), worst);
+        let earliest = hours
+            .map(|h| h.conditions)
+            .find(|c| c.severity() == worst)
+            .unwrap();
+        assert_eq!(window.conditions, earliest);
+    }
+    // The forecast opens with heavy rain.
+    let windows = forecast.resample_every(SignedDuration::from_hours(4));
+    assert_eq!(windows[0].conditions, Conditions::HeavyRain);
+    Ok(())
+}
+
+#[test]
+pub fn day_parts() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    let london = TimeZone::posix("GMT0BST,M3.5.0/1,M10.5.0")?;
+    let windows = forecast.resample_at(&london, &DAY_PARTS);
+    let starts: Vec<String> = windows
+        .iter()
+        .map(|w| w.start.strftime("%d %H:%M").to_string())
+        .collect();
+    assert_eq!(
+        starts,
+        [
+            "05 06:00", "05 12:00", "05 18:00", "06 00:00", "06 06:00", "06 12:00", "06 18:00",
+            "07 00:00", "07 06:00",
+        ]
+    );
+    // 10:00 UTC is 11:00 BST, so the first morning has one hour.
+    assert_eq!(windows[0].predictions, 1);
+    assert!(windows[1..8].iter().all(|w| w.predictions == 6));
+    assert_eq!(windows[8].predictions, 6);
+    let total: usize = windows.iter().map(|w| w.predictions).sum();
+    assert_eq!(total, forecast.predictions.len());
+    Ok(())
+}
+
+#[test]
+pub fn day_parts_across_clock_change() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    // Clocks go forward at 02:00 on the first Thursday of July (6 July 2023).
+    let tz = TimeZone::posix("AAA0BBB,M7.1.4/2,M10.5.0")?;
+    let windows = forecast.resample_at(&tz, &DAY_PARTS);
+    let night = windows
+        .iter()
+        .find(|w| w.start.strftime("%d %H:%M").to_string() == "06 00:00")
+        .unwrap();
+    assert_eq!(
+        night.end.duration_since(&night.start),
+        SignedDuration::from_hours(5)
+    );
+    assert_eq!(night.predictions, 5);
+    Ok(())
+}
+
+#[test]
+pub fn unordered_and_empty_times() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    let london = TimeZone::posix("GMT0BST,M3.5.0/1,M10.5.0")?;
+    let mut reversed = DAY_PARTS;
+    reversed.reverse();
+    assert_eq!(
+        forecast.resample_at(&london, &reversed),
+        forecast.resample_at(&london, &DAY_PARTS)
+    );
+    assert!(forecast.resample_at(&london, &[]).is_empty());
+    assert!(forecast.resample_every(SignedDuration::ZERO).is_empty());
+    Ok(())
+}
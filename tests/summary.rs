@@ -0,0 +1,32 @@
+use jiff::SignedDuration;
+use jiff::tz::TimeZone;
+use rjw_metoffice::{Daily, Forecast, Hourly};
+
+const HOURLY_SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY_SAMPLE: &str = include_str!("global-spot-daily-sample.json");
+
+fn london() -> TimeZone {
+    TimeZone::posix("GMT0BST,M3.5.0/1,M10.5.0").unwrap()
+}
+
+#[test]
+pub fn hourly_summary_groups_similar_hours_in_local_time() {
+    let f: Forecast<Hourly> = HOURLY_SAMPLE.parse().expect("Failed to parse");
+    let summary = f.summary(&london(), SignedDuration::from_hours(24));
+    assert_eq!(
+        summary,
+        "Heavy rain this morning, light rain from 12:00, cloudy from 14:00, \
+         partly cloudy from 18:00, cloudy from 10:00 tomorrow, \
+         highs of 19°C, lows of 12°C, gusts up to 26 mph"
+    );
+}
+
+#[test]
+pub fn daily_summary_starts_today() {
+    let f: Forecast<Daily> = DAILY_SAMPLE.parse().expect("Failed to parse");
+    let summary = f.summary(&london());
+    assert_eq!(summary.len(), 7);
+    assert_eq!(summary[0], "Today: light rain, 11–18°C");
+    assert_eq!(summary[1], "Tomorrow: cloudy, 12–21°C");
+    assert_eq!(summary[4], "Sunday: heavy rain showers, 14–21°C");
+}
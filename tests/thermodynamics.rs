@@ -0,0 +1,87 @@
+use rjw_metoffice::thermodynamics::*;
+use rjw_metoffice::units::{Celsius, MetresPerSecond, Pascals, Percentage};
+use rjw_metoffice::{Forecast, Hourly};
+
+const SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+
+fn assert_close(actual: f32, expected: f32, tolerance: f32) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "{actual} is not within {tolerance} of {expected}"
+    );
+}
+
+/// Saturation vapour pressures from the WMO "Guide to Instruments and Methods of Observation"
+#[test]
+pub fn saturation_vapour_pressure_reference_values() {
+    for (t, e) in [(0.0, 6.112), (10.0, 12.28), (20.0, 23.39), (30.0, 42.46)] {
+        assert_close(saturation_vapour_pressure(Celsius(t)).0, e, 0.1);
+    }
+}
+
+#[test]
+pub fn humidity_reference_values() {
+    // Saturated air at 20°C holds 17.3 g/m³ and 14.7 g/kg at standard pressure.
+    assert_close(absolute_humidity(Celsius(20.0), Celsius(20.0)).0, 17.3, 0.1);
+    assert_close(mixing_ratio(Celsius(20.0), Pascals(101_325)).0, 14.7, 0.1);
+    assert_close(vapour_pressure(Celsius(10.0)).0, 12.28, 0.05);
+}
+
+/// Worked example from Stull (2011)
+#[test]
+pub fn wet_bulb_reference_values() {
+    assert_close(
+        wet_bulb_temperature(Celsius(20.0), Percentage(50.0)).0,
+        13.7,
+        0.05,
+    );
+}
+
+/// Values from the US National Weather Service heat index chart, in °F
+#[test]
+pub fn heat_index_reference_values() {
+    let f_to_c = |f: f32| (f - 32.0) * 5.0 / 9.0;
+    for (t, rh, hi) in [
+        (90.0, 60.0, 100.0),
+        (96.0, 65.0, 121.0),
+        (80.0, 40.0, 80.0),
+        (104.0, 40.0, 119.0),
+    ] {
+        let actual = heat_index(Celsius(f_to_c(t)), Percentage(rh)).0;
+        assert_close(actual, f_to_c(hi), 0.75);
+    }
+}
+
+/// Values from the Environment Canada humidex and wind chill tables
+#[test]
+pub fn humidex_and_wind_chill_reference_values() {
+    assert_close(humidex(Celsius(30.0), Celsius(15.0)).0, 34.0, 0.5);
+    assert_close(humidex(Celsius(35.0), Celsius(25.0)).0, 47.0, 0.5);
+
+    let kmh = |v: f32| MetresPerSecond(v / 3.6);
+    assert_close(wind_chill(Celsius(-10.0), kmh(20.0)).unwrap().0, -18.0, 0.5);
+    assert_close(wind_chill(Celsius(-30.0), kmh(50.0)).unwrap().0, -49.0, 0.5);
+    assert_eq!(wind_chill(Celsius(15.0), kmh(20.0)), None);
+    assert_eq!(wind_chill(Celsius(-10.0), kmh(2.0)), None);
+}
+
+#[test]
+pub fn cloud_base_from_spread() {
+    assert_close(cloud_base(Celsius(20.0), Celsius(15.0)).0, 625.0, 0.01);
+    assert_eq!(cloud_base(Celsius(10.0), Celsius(10.0)).0, 0.0);
+}
+
+#[test]
+pub fn hourly_methods() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    for h in &forecast.predictions {
+        assert!(h.wet_bulb_temperature() <= h.temperature);
+        assert!(h.wet_bulb_temperature() >= h.dew_point_temperature);
+        assert!(h.absolute_humidity().0 > 0.0);
+        assert!(h.mixing_ratio().0 > 0.0);
+        assert!(h.cloud_base().0 >= 0.0);
+        // July in Exeter is too warm for wind chill.
+        assert_eq!(h.wind_chill(), None);
+    }
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use jiff::{SignedDuration, Timestamp, Zoned};
+use rjw_metoffice::timeline::{Point, Resolution, Timeline};
+use rjw_metoffice::units::{
+    Celsius, Conditions, Coordinates, Degrees, Metres, MetresPerSecond, Millimetres, Pascals,
+    Percentage, UvIndex, Visibility,
+};
+use rjw_metoffice::{Daily, Error, Forecast, Hourly, ThreeHourly};
+
+const HOURLY: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY: &str = include_str!("global-spot-daily-sample.json");
+
+fn zoned(s: &str) -> Zoned {
+    s.parse().unwrap()
+}
+
+fn three_hourly_prediction(time: Zoned) -> ThreeHourly {
+    ThreeHourly {
+        time,
+        conditions: Conditions::Cloudy,
+        temperature_maximum: Celsius(18.0),
+        temperature_minimum: Celsius(14.0),
+        temperature_feels_like: Celsius(15.0),
+        wind_speed: MetresPerSecond(4.0),
+        wind_direction: Degrees(270.0),
+        wind_gust_speed: MetresPerSecond(8.0),
+        wind_gust_three_hourly_maximum: MetresPerSecond(9.0),
+        visibility: Visibility(20_000.0),
+        relative_humidity: Percentage(70.0),
+        pressure: Pascals(101_500),
+        uv_index: UvIndex(1),
+        precipitation_total: Millimetres(0.0),
+        snow_total: Millimetres(0.0),
+        precipitation_probability: Percentage(10.0),
+        rain_probability: Percentage(10.0),
+        heavy_rain_probability: Percentage(5.0),
+        snow_probability: Percentage(0.0),
+        heavy_snow_probability: Percentage(0.0),
+        hail_probability: Percentage(0.0),
+        lightning_probability: Percentage(0.0),
+    }
+}
+
+/// Three-hourly forecast for the sample site, with `count` periods from 09:00 on 5 July 2023
+fn three_hourly(count: i64, coordinates: Coordinates, run: &str) -> Forecast<ThreeHourly> {
+    let first = zoned("2023-07-05T09:00Z[UTC]");
+    Forecast {
+        location_name: "Exeter Met Office".into(),
+        coordinates,
+        requested_point_distance: Metres(27.9),
+        predictions_made_at: zoned(run),
+        predictions: (0..count)
+            .map(|i| three_hourly_prediction(&first + SignedDuration::from_hours(3 * i)))
+            .collect(),
+    }
+}
+
+fn timeline(three_hourly_count: i64) -> anyhow::Result<Timeline> {
+    let hourly: Forecast<Hourly> = HOURLY.parse()?;
+    let daily: Forecast<Daily> = DAILY.parse()?;
+    let three_hourly = three_hourly(
+        three_hourly_count,
+        hourly.coordinates,
+        "2023-07-05T09:00Z[UTC]",
+    );
+    Ok(Timeline::new(hourly, three_hourly, daily)?)
+}
+
+#[test]
+pub fn finest_resolution_without_gaps() -> anyhow::Result<()> {
+    let timeline = timeline(56)?;
+    let points: Vec<Point> = timeline.points().collect();
+    let hourly = points
+        .iter()
+        .take_while(|p| p.resolution() == Resolution::Hourly)
+        .count();
+    // Hourly from 10:00 on the 5th up to the three-hourly period starting 09:00 on the 7th.
+    assert_eq!(hourly, 47);
+    assert_eq!(
+        points[hourly].time().timestamp(),
+        "2023-07-07T09:00Z".parse::<Timestamp>()?
+    );
+    assert!(
+        points[hourly..]
+            .iter()
+            .all(|p| p.resolution() == Resolution::ThreeHourly)
+    );
+    // Three-hourly runs out at 09:00 on the 12th, 7 days after the first three-hourly
+    // period, so no daily predictions are needed.
+    assert_eq!(points.len(), 47 + 56 - 16);
+    for pair in points.windows(2) {
+        let end = pair[0].time() + pair[0].resolution().period();
+        assert_eq!(&end, pair[1].time());
+    }
+    Ok(())
+}
+
+#[test]
+pub fn daily_beyond_three_hourly() -> anyhow::Result<()> {
+    let timeline = timeline(40)?;
+    let points: Vec<Point> = timeline.points().collect();
+    let (last_three_hourly, daily) = {
+        let i = points
+            .iter()
+            .position(|p| p.resolution() == Resolution::Daily)
+            .unwrap();
+        (&points[i - 1], &points[i..])
+    };
+    // The last three-hourly period is 06:00–09:00 on the 10th.
+    assert_eq!(
+        last_three_hourly.time().timestamp(),
+        "2023-07-10T06:00Z".parse::<Timestamp>()?
+    );
+    let dates: Vec<String> = daily.iter().map(|p| p.time().date().to_string()).collect();
+    assert_eq!(dates, ["2023-07-11"]);
+    assert!(matches!(daily[0], Point::Daily(_)));
+    Ok(())
+}
+
+#[test]
+pub fn days_skip_the_past() -> anyhow::Result<()> {
+    let timeline = timeline(56)?;
+    let dates: Vec<String> = timeline.days().map(|d| d.time.date().to_string()).collect();
+    assert_eq!(dates.len(), 7);
+    assert_eq!(dates[0], "2023-07-05");
+    Ok(())
+}
+
+#[test]
+pub fn model_run_spread() -> anyhow::Result<()> {
+    // Hourly and daily samples were both run at 10:00, the three-hourly at 09:00.
+    let timeline = timeline(56)?;
+    assert_eq!(timeline.model_run_spread(), SignedDuration::from_hours(1));
+    Ok(())
+}
+
+#[test]
+pub fn different_sites() -> anyhow::Result<()> {
+    let hourly: Forecast<Hourly> = HOURLY.parse()?;
+    let daily: Forecast<Daily> = DAILY.parse()?;
+    // Exeter Airport, about 8 km east of the Met Office.
+    let airport = Coordinates::try_from([-3.414, 50.734, 30.0])?;
+    let three_hourly = three_hourly(56, airport, "2023-07-05T09:00Z[UTC]");
+    assert!(matches!(
+        Timeline::new(hourly, three_hourly, daily),
+        Err(Error::DifferentForecastSites)
+    ));
+    Ok(())
+}
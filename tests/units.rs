@@ -0,0 +1,75 @@
+use rjw_metoffice::units::*;
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 0.01
+}
+
+#[test]
+pub fn temperature_conversions() {
+    for (c, f, k) in [
+        (0.0, 32.0, 273.15),
+        (100.0, 212.0, 373.15),
+        (-40.0, -40.0, 233.15),
+        (17.64, 63.752, 290.79),
+    ] {
+        assert!(close(Fahrenheit::from(Celsius(c)).0, f), "{c}°C");
+        assert!(close(Kelvin::from(Celsius(c)).0, k), "{c}°C");
+        assert!(close(Celsius::from(Fahrenheit(f)).0, c), "{f}°F");
+        assert!(close(Celsius::from(Kelvin(k)).0, c), "{k} K");
+    }
+}
+
+#[test]
+pub fn speed_conversions() {
+    let s = MetresPerSecond(10.0);
+    assert!(close(MilesPerHour::from(s).0, 22.369));
+    assert!(close(KilometresPerHour::from(s).0, 36.0));
+    assert!(close(Knots::from(s).0, 19.438));
+    assert!(close(MetresPerSecond::from(Knots(19.438)).0, 10.0));
+    assert!(close(MetresPerSecond::from(MilesPerHour(22.369)).0, 10.0));
+}
+
+#[test]
+pub fn pressure_conversions() {
+    let standard = Pascals(101_325);
+    assert!(close(Hectopascals::from(standard).0, 1013.25));
+    assert!(close(Millibars::from(standard).0, 1013.25));
+    assert!(close(InchesOfMercury::from(standard).0, 29.921));
+    assert!(close(MillimetresOfMercury::from(standard).0, 760.0));
+    assert_eq!(Pascals::from(Hectopascals(1013.25)), standard);
+    assert_eq!(Pascals::from(InchesOfMercury(29.92)), Pascals(101_321));
+}
+
+#[test]
+pub fn length_conversions() {
+    assert!(close(Inches::from(Millimetres(25.4)).0, 1.0));
+    assert!(close(Feet::from(Metres(27.0)).0, 88.583));
+    assert!(close(Miles::from(Metres(1609.344)).0, 1.0));
+    assert!(close(Kilometres::from(Metres(10_000.0)).0, 10.0));
+    assert!(close(Metres::from(Feet(1000.0)).0, 304.8));
+}
+
+#[test]
+pub fn display_uses_default_or_requested_precision() {
+    let c = Celsius(17.64);
+    assert_eq!(c.to_string(), "17.64°C");
+    assert_eq!(format!("{c:.0}"), "18°C");
+    assert_eq!(Fahrenheit::from(c).to_string(), "63.8°F");
+    assert_eq!(format!("{:.0}", Fahrenheit::from(c)), "64°F");
+    assert_eq!(Kelvin::from(c).to_string(), "290.79 K");
+    assert_eq!(Knots::from(MetresPerSecond(10.0)).to_string(), "19.4 kn");
+    assert_eq!(
+        format!("{:.2}", MilesPerHour::from(MetresPerSecond(10.0))),
+        "22.37 mph"
+    );
+    assert_eq!(
+        Hectopascals::from(Pascals(101_325)).to_string(),
+        "1013.2 hPa"
+    );
+    assert_eq!(
+        InchesOfMercury::from(Pascals(101_325)).to_string(),
+        "29.92 inHg"
+    );
+    assert_eq!(Feet::from(Metres(27.0)).to_string(), "89 ft");
+    assert_eq!(format!("{:.1}", Metres(27.0)), "27.0m");
+}
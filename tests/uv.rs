@@ -0,0 +1,58 @@
+use jiff::tz::TimeZone;
+use rjw_metoffice::units::{UvCategory, UvIndex};
+use rjw_metoffice::{Forecast, Hourly};
+
+const SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+
+#[test]
+pub fn who_bands() {
+    use UvCategory::*;
+    let expected = [
+        Low, Low, Low, Moderate, Moderate, Moderate, High, High, VeryHigh, VeryHigh, VeryHigh,
+        Extreme, Extreme,
+    ];
+    for (index, category) in expected.into_iter().enumerate() {
+        assert_eq!(UvIndex(index as u8).category(), category, "{index}");
+    }
+    assert_eq!(UvIndex(u8::MAX).category(), Extreme);
+}
+
+#[test]
+pub fn wording_and_colours() {
+    assert_eq!(UvCategory::VeryHigh.to_string(), "Very high");
+    assert_eq!(UvCategory::Extreme.colour(), "#6b49c8");
+    assert_ne!(
+        UvIndex(7).advice_message(),
+        UvIndex(11).advice_message(),
+        "high and extreme should have different advice"
+    );
+    assert!(!UvIndex(2).protection_needed());
+    assert!(UvIndex(3).protection_needed());
+}
+
+#[test]
+pub fn protection_windows_in_local_time() -> anyhow::Result<()> {
+    let forecast: Forecast<Hourly> = SAMPLE.parse()?;
+    let london = TimeZone::posix("GMT0BST,M3.5.0/1,M10.5.0")?;
+    let windows = forecast.uv_protection_windows(&london);
+    let times: Vec<_> = windows
+        .iter()
+        .map(|w| {
+            (
+                w.start.strftime("%d %H:%M").to_string(),
+                w.end.strftime("%d %H:%M").to_string(),
+                w.peak,
+            )
+        })
+        .collect();
+    assert_eq!(
+        times,
+        [
+            ("05 11:00".into(), "05 18:00".into(), UvIndex(7)),
+            ("06 09:00".into(), "06 18:00".into(), UvIndex(6)),
+            // The forecast ends at 11:00 BST, while protection is still needed.
+            ("07 08:00".into(), "07 12:00".into(), UvIndex(6)),
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use rjw_metoffice::units::{Visibility, VisibilityCategory};
+use rjw_metoffice::{Daily, Forecast, Hourly};
+
+const HOURLY_SAMPLE: &str = include_str!("global-spot-hourly-sample.json");
+const DAILY_SAMPLE: &str = include_str!("global-spot-daily-sample.json");
+
+#[test]
+pub fn category_boundaries() {
+    use VisibilityCategory::*;
+    let cases = [
+        (0.0, VeryPoor),
+        (999.0, VeryPoor),
+        (1_000.0, Poor),
+        (3_999.0, Poor),
+        (4_000.0, Moderate),
+        (9_999.0, Moderate),
+        (10_000.0, Good),
+        (19_999.0, Good),
+        (20_000.0, VeryGood),
+        (40_000.0, VeryGood),
+        (40_001.0, Excellent),
+        (75_000.0, Excellent),
+    ];
+    for (metres, category) in cases {
+        assert_eq!(Visibility(metres).category(), category, "{metres}m");
+    }
+}
+
+#[test]
+pub fn fog_is_below_one_kilometre() {
+    assert!(Visibility(999.0).is_fog());
+    assert!(!Visibility(1_000.0).is_fog());
+}
+
+#[test]
+pub fn labels() {
+    assert_eq!(VisibilityCategory::VeryGood.to_string(), "Very good");
+    assert_eq!(VisibilityCategory::VeryPoor.description(), "Less than 1 km");
+    assert_eq!(Visibility(12_345.0).to_string(), "12345m");
+}
+
+#[test]
+pub fn visibility_fields_use_newtype() -> anyhow::Result<()> {
+    let hourly: Forecast<Hourly> = HOURLY_SAMPLE.parse()?;
+    let v: Visibility = hourly.predictions[0].visibility;
+    assert_eq!(v, Visibility(6_668.0));
+    assert_eq!(v.category(), VisibilityCategory::Moderate);
+    assert_eq!(
+        hourly.predictions[1].visibility.category(),
+        VisibilityCategory::Good
+    );
+
+    let daily: Forecast<Daily> = DAILY_SAMPLE.parse()?;
+    let v: Visibility = daily.predictions[0].night.visibility;
+    assert_eq!(v, Visibility(15_035.0));
+    assert_eq!(v.category(), VisibilityCategory::Good);
+    Ok(())
+}